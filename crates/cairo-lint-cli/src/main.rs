@@ -2,32 +2,37 @@ pub mod helpers;
 
 use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::fs;
+use std::io;
 use std::path::PathBuf;
+use std::sync::Arc;
 
-use annotate_snippets::Renderer;
 use anyhow::{anyhow, Result};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::project::{update_crate_root, update_crate_roots_from_project_config};
-use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_diagnostics::{DiagnosticEntry, Maybe};
+use cairo_lang_diagnostics::DiagnosticEntry;
 use cairo_lang_filesystem::db::{init_dev_corelib, FilesGroup, CORELIB_CRATE_NAME};
 use cairo_lang_filesystem::ids::{CrateLongId, FileId};
-use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
 use cairo_lang_starknet::starknet_plugin_suite;
 use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_utils::{Upcast, UpcastMut};
-use cairo_lint_core::diagnostics::format_diagnostic;
+use cairo_lint_core::config::{CairoLintConfig, CairoLintConfigLayer};
+use cairo_lint_core::diagnostics::{
+    crate_diagnostics, format_diagnostic, limit_diagnostics, stream_crate_diagnostics, write_json, write_json_lines,
+    write_review, ColorChoice, LintSummary, Ruleset,
+};
 use cairo_lint_core::fix::{apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix};
-use cairo_lint_core::plugin::cairo_lint_plugin_suite;
-use clap::Parser;
+use cairo_lint_core::plugin::{cairo_lint_plugin_suite_with_config, cairo_lint_plugin_suite_with_timings};
+use cairo_lint_core::profiling::LintTimings;
+use clap::{Parser, ValueEnum};
 use helpers::*;
-use scarb_metadata::{MetadataCommand, PackageMetadata, TargetMetadata};
+use scarb_metadata::{Metadata, MetadataCommand, PackageMetadata, TargetMetadata};
 use scarb_ui::args::{PackagesFilter, VerbositySpec};
 use scarb_ui::components::Status;
-use scarb_ui::{OutputFormat, Ui};
+use scarb_ui::{OutputFormat, Ui, Verbosity};
 use smol_str::SmolStr;
 
 #[derive(Parser, Debug)]
@@ -49,11 +54,64 @@ struct Args {
     /// Should fix the lint when it can.
     #[arg(short, long, default_value_t = false)]
     pub fix: bool,
+    /// When to color the diagnostics.
+    #[arg(long, value_enum, default_value_t = Color::Auto)]
+    pub color: Color,
+    /// Format of the diagnostics.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+    /// Should fail when there are diagnostics, errors or warnings.
+    #[arg(long, default_value_t = false)]
+    pub deny_warnings: bool,
+    /// Number of diagnostics reported per target, the others being replaced by a notice.
+    #[arg(long)]
+    pub max_diagnostics: Option<usize>,
+    /// Directory the files of the diagnostics are written relative to, like the repository root.
+    #[arg(long)]
+    pub base_path: Option<PathBuf>,
+    /// Should report the time spent in each lint once done.
+    #[arg(long, default_value_t = false)]
+    pub profile: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// Rendered with the source code, for humans.
+    Text,
+    /// A JSON array of the diagnostics of each target.
+    Json,
+    /// One JSON object per line, written as soon as the diagnostics of a module are known.
+    JsonLines,
+    /// Only the number of errors and warnings once every target is linted, for CI logs.
+    Summary,
+    /// A JSON array of the diagnostics of each target with the lines to replace to fix them, for
+    /// the bots commenting pull requests.
+    Review,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Color {
+    /// Only when printing to a terminal, unless `NO_COLOR` is set.
+    Auto,
+    Always,
+    Never,
+}
+
+impl From<Color> for ColorChoice {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Auto => ColorChoice::Auto,
+            Color::Always => ColorChoice::Always,
+            Color::Never => ColorChoice::Never,
+        }
+    }
 }
 
 fn main() -> Result<()> {
     let args: Args = Args::parse();
-    let ui = Ui::new(args.verbose.clone().into(), OutputFormat::Text);
+    // Nothing but the diagnostics must end up in the output of the machine readable formats.
+    let verbosity = if args.format == Format::Text { args.verbose.clone().into() } else { Verbosity::Quiet };
+    let ui = Ui::new(verbosity, OutputFormat::Text);
     if let Err(err) = main_inner(&ui, args) {
         ui.anyhow(&err);
         std::process::exit(1);
@@ -78,9 +136,40 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
     // never be linted unless specified with the `--test` flag
 
     let matched = args.packages_filter.match_many(&metadata)?;
+    // Shared by all the compilation units so that the report covers the whole run.
+    let timings = args.profile.then(|| Arc::new(LintTimings::default()));
+    let mut summary = LintSummary::default();
+    let workspace_layer = workspace_config_layer(&metadata)?;
 
     // Let's lint everything requested
     for package in matched {
+        // Get the lint configuration of the package from the `[tool.cairo-lint]` sections of the
+        // manifests of the workspace root and of the package, and from the command line, each one
+        // taking precedence over the ones before it.
+        let workspace_layer = if package.manifest_path == metadata.workspace.manifest_path {
+            // Already read as the layer of the package.
+            CairoLintConfigLayer::default()
+        } else {
+            workspace_layer.clone()
+        };
+        let command_line_layer = CairoLintConfigLayer {
+            deny_warnings: args.deny_warnings.then_some(true),
+            max_diagnostics: args.max_diagnostics,
+            base_path: args.base_path.clone(),
+            ..CairoLintConfigLayer::default()
+        };
+        let mut lint_config = CairoLintConfig::merged([workspace_layer, config_layer(&package)?, command_line_layer]);
+        // The paths of the files are canonical, so has to be the base path to prefix them.
+        lint_config.base_path = lint_config.base_path.map(|base_path| base_path.canonicalize()).transpose()?;
+        // Identify the lints that are run so that CI can notice when they change between runs.
+        let ruleset = Ruleset::new(&lint_config);
+        ui.print(Status::new("Ruleset", &ruleset.to_string()));
+        for name in lint_config.unknown_lints() {
+            ui.warn(format!("Unknown lint `{name}` in the configuration of package {}, it's ignored", package.name));
+        }
+        if lint_config.disables_all_lints() {
+            ui.warn(format!("Every lint is disabled for package {}, no diagnostic will be reported", package.name));
+        }
         // Get the current package metadata
         let compilation_units = if args.test {
             let tests_targets = find_testable_targets(&package);
@@ -107,7 +196,10 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
             let mut db = RootDatabase::builder()
                 .with_plugin_suite(get_default_plugin_suite())
                 .with_plugin_suite(test_plugin_suite())
-                .with_plugin_suite(cairo_lint_plugin_suite())
+                .with_plugin_suite(match &timings {
+                    Some(timings) => cairo_lint_plugin_suite_with_timings(lint_config.clone(), timings.clone()),
+                    None => cairo_lint_plugin_suite_with_config(lint_config.clone()),
+                })
                 .with_plugin_suite(starknet_plugin_suite())
                 .with_cfg(to_cairo_cfg(&compilation_unit.cfg))
                 .build()?;
@@ -135,25 +227,48 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
             }
             let crate_id =
                 Upcast::<dyn FilesGroup>::upcast(&db).intern_crate(CrateLongId::Real(SmolStr::new(&package.name)));
-            // Get all the diagnostics
-            let mut diags = Vec::new();
-
-            for module_id in &*db.crate_modules(crate_id) {
-                if let Maybe::Ok(module_diags) = db.module_semantic_diagnostics(*module_id) {
-                    diags.push(module_diags);
+            // Get all the diagnostics, sorted by location. Only the reported ones are limited, all
+            // of them are fixed and counted.
+            let max_diagnostics = lint_config.max_diagnostics;
+            let base_path = lint_config.base_path.as_deref();
+            let diagnostics = match args.format {
+                Format::Text => {
+                    let diagnostics = crate_diagnostics(&db, crate_id);
+                    let renderer = ColorChoice::from(args.color).renderer();
+                    limit_diagnostics(diagnostics.iter().cloned(), max_diagnostics)
+                        .for_each(|diag| ui.print(format_diagnostic(&diag, &db, &renderer, base_path)));
+                    diagnostics
                 }
-            }
-
-            let renderer = Renderer::styled();
-
-            let diagnostics = diags
-                .iter()
-                .flat_map(|diags| {
-                    let all_diags = diags.get_all();
-                    all_diags.iter().for_each(|diag| ui.print(format_diagnostic(diag, &db, &renderer)));
-                    all_diags
-                })
-                .collect::<Vec<_>>();
+                Format::Json => {
+                    let diagnostics = crate_diagnostics(&db, crate_id);
+                    let reported: Vec<_> = limit_diagnostics(diagnostics.iter().cloned(), max_diagnostics).collect();
+                    write_json(&db, &reported, base_path, &ruleset, io::stdout().lock())?;
+                    diagnostics
+                }
+                // The diagnostics of the whole crate are only held in memory when they're needed to
+                // fix the code.
+                Format::JsonLines => {
+                    let reported = limit_diagnostics(stream_crate_diagnostics(&db, crate_id), max_diagnostics);
+                    write_json_lines(&db, reported, base_path, &ruleset, io::stdout().lock())?;
+                    if args.fix { crate_diagnostics(&db, crate_id) } else { Vec::new() }
+                }
+                Format::Review => {
+                    let diagnostics = crate_diagnostics(&db, crate_id);
+                    let reported: Vec<_> = limit_diagnostics(diagnostics.iter().cloned(), max_diagnostics).collect();
+                    write_review(&db, &reported, base_path, &ruleset, io::stdout().lock())?;
+                    diagnostics
+                }
+                // Only counted, the summary is printed once every target is linted.
+                Format::Summary => crate_diagnostics(&db, crate_id),
+            };
+            // The diagnostics of the modules are cached, going through them again is cheap.
+            let unit_summary = match args.format {
+                Format::JsonLines if !args.fix => {
+                    LintSummary::new(stream_crate_diagnostics(&db, crate_id), &lint_config)
+                }
+                _ => LintSummary::new(&diagnostics, &lint_config),
+            };
+            summary = summary.merge(unit_summary);
 
             if args.fix {
                 // Handling unused imports separately as we need to run pre-analysis on the diagnostics.
@@ -216,9 +331,41 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
             }
         }
     }
+    // Written to stderr to keep the output of the machine readable formats parseable.
+    if let Some(timings) = timings {
+        eprint!("{timings}");
+    }
+    if args.format == Format::Summary {
+        println!("{summary}");
+    }
+    if summary.should_fail {
+        return Err(anyhow!("Found {} errors and {} warnings", summary.errors, summary.warnings));
+    }
     Ok(())
 }
 
 fn find_testable_targets(package: &PackageMetadata) -> Vec<&TargetMetadata> {
     package.targets.iter().filter(|target| target.kind == "test").collect()
 }
+
+/// Reads the `[tool.cairo-lint]` section of the manifest at the root of the workspace, its base
+/// path being relative to the root of the workspace. Scarb doesn't give the tool sections of a
+/// virtual workspace, it has no package, so the manifest is read directly.
+fn workspace_config_layer(metadata: &Metadata) -> Result<CairoLintConfigLayer> {
+    let manifest = fs::read_to_string(&metadata.workspace.manifest_path)?;
+    let mut layer = CairoLintConfigLayer::from_manifest(&manifest)?;
+    layer.base_path = layer.base_path.map(|base_path| metadata.workspace.root.as_std_path().join(base_path));
+    Ok(layer)
+}
+
+/// Reads the `[tool.cairo-lint]` section of the manifest of a package, its base path being relative
+/// to the root of the package.
+fn config_layer(package: &PackageMetadata) -> Result<CairoLintConfigLayer> {
+    let mut layer = package
+        .tool_metadata("cairo-lint")
+        .map(|config| serde_json::from_value::<CairoLintConfigLayer>(config.clone()))
+        .transpose()?
+        .unwrap_or_default();
+    layer.base_path = layer.base_path.map(|base_path| package.root.as_std_path().join(base_path));
+    Ok(layer)
+}
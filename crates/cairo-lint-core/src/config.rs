@@ -0,0 +1,297 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::plugin::CairoLintKind;
+
+/// Configuration of the linter.
+///
+/// It's read from the `[tool.cairo-lint]` section of the package manifest, every field being
+/// optional:
+///
+/// ```toml
+/// [tool.cairo-lint]
+/// public-api-only = true
+/// default = false
+/// large-struct-threshold = 8
+/// deny-warnings = true
+/// todo-markers = ["TODO", "FIXME"]
+/// magic-number-threshold = 3
+/// ignored-magic-numbers = [0, 1, 2]
+/// duplicate-body-min-statements = 2
+/// lint-extern-call-arguments = true
+/// max-diagnostics = 100
+/// base-path = "../.."
+///
+/// [tool.cairo-lint.lints]
+/// double_parens = true
+///
+/// [[tool.cairo-lint.overrides]]
+/// path = "src/contracts/**"
+/// lints = { panic_in_result = true }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct CairoLintConfig {
+    /// Only run the lints concerning the public api of a crate on `pub` items.
+    pub public_api_only: bool,
+    /// Whether the lints that aren't listed in [`Self::lints`] are run. Lints that are disabled by
+    /// default are only run when listed.
+    pub default: bool,
+    /// Lints explicitly enabled or disabled, by name.
+    pub lints: BTreeMap<String, bool>,
+    /// Number of fields past which `large_struct_by_value` reports a struct taken by value, the
+    /// fields of nested structs and tuples included.
+    pub large_struct_threshold: usize,
+    /// Lints enabled or disabled for some files only, taking precedence over [`Self::lints`].
+    pub overrides: Vec<LintOverride>,
+    /// Whether a run with diagnostics fails, warnings included. Without it, a run doesn't fail
+    /// because of its diagnostics.
+    pub deny_warnings: bool,
+    /// Words marking the comments reported by `todo_comment`.
+    pub todo_markers: Vec<String>,
+    /// Number of functions a number must appear in to be reported by `repeated_magic_number`.
+    pub magic_number_threshold: usize,
+    /// Numbers too common to be worth a constant, never reported by `repeated_magic_number`.
+    pub ignored_magic_numbers: Vec<i64>,
+    /// Number of statements a function body must have to be reported by `duplicate_function_body`.
+    pub duplicate_body_min_statements: usize,
+    /// Whether the conversion lints are run on the arguments of the calls to `extern` functions,
+    /// the libfuncs among them. Such conversions are often deliberate in low-level code.
+    pub lint_extern_call_arguments: bool,
+    /// Number of diagnostics past which the others are replaced by a notice telling how many were
+    /// left out. All of them are reported when it's `None`.
+    pub max_diagnostics: Option<usize>,
+    /// Directory the files of the diagnostics are written relative to, e.g. the root of the
+    /// repository for CI. Files outside of it keep their absolute path. Relative to the root of the
+    /// package when read from the manifest.
+    pub base_path: Option<PathBuf>,
+}
+
+impl Default for CairoLintConfig {
+    fn default() -> Self {
+        Self {
+            public_api_only: false,
+            default: true,
+            lints: BTreeMap::new(),
+            large_struct_threshold: 8,
+            overrides: Vec::new(),
+            deny_warnings: false,
+            todo_markers: ["TODO", "FIXME", "XXX"].map(String::from).to_vec(),
+            magic_number_threshold: 3,
+            ignored_magic_numbers: vec![0, 1],
+            duplicate_body_min_statements: 2,
+            lint_extern_call_arguments: false,
+            max_diagnostics: None,
+            base_path: None,
+        }
+    }
+}
+
+impl CairoLintConfig {
+    /// Builds a configuration from layers, each one taking precedence over the ones before it,
+    /// e.g. the ones of the workspace, of the package and of the command line. The keys missing
+    /// from every layer keep their default value.
+    pub fn merged(layers: impl IntoIterator<Item = CairoLintConfigLayer>) -> Self {
+        layers.into_iter().fold(Self::default(), Self::merge)
+    }
+
+    /// Applies the keys set by a layer over this configuration. The lints it lists are merged one
+    /// by one with [`Self::lints`], and its overrides come after the ones already there, so they
+    /// win between equally specific ones. Lists like [`Self::todo_markers`] are replaced.
+    pub fn merge(self, layer: CairoLintConfigLayer) -> Self {
+        let CairoLintConfigLayer {
+            public_api_only,
+            default,
+            lints,
+            large_struct_threshold,
+            overrides,
+            deny_warnings,
+            todo_markers,
+            magic_number_threshold,
+            ignored_magic_numbers,
+            duplicate_body_min_statements,
+            lint_extern_call_arguments,
+            max_diagnostics,
+            base_path,
+        } = layer;
+        Self {
+            public_api_only: public_api_only.unwrap_or(self.public_api_only),
+            default: default.unwrap_or(self.default),
+            lints: self.lints.into_iter().chain(lints).collect(),
+            large_struct_threshold: large_struct_threshold.unwrap_or(self.large_struct_threshold),
+            overrides: self.overrides.into_iter().chain(overrides).collect(),
+            deny_warnings: deny_warnings.unwrap_or(self.deny_warnings),
+            todo_markers: todo_markers.unwrap_or(self.todo_markers),
+            magic_number_threshold: magic_number_threshold.unwrap_or(self.magic_number_threshold),
+            ignored_magic_numbers: ignored_magic_numbers.unwrap_or(self.ignored_magic_numbers),
+            duplicate_body_min_statements: duplicate_body_min_statements.unwrap_or(self.duplicate_body_min_statements),
+            lint_extern_call_arguments: lint_extern_call_arguments.unwrap_or(self.lint_extern_call_arguments),
+            max_diagnostics: max_diagnostics.or(self.max_diagnostics),
+            base_path: base_path.or(self.base_path),
+        }
+    }
+
+    /// Checks if a lint should be run with this configuration.
+    pub fn is_enabled(&self, kind: CairoLintKind) -> bool {
+        self.lints.get(kind.name()).copied().unwrap_or(self.default && kind.is_enabled_by_default())
+    }
+
+    /// Checks if a lint should be run on a file with this configuration, the most specific of the
+    /// overrides matching the file and listing the lint taking precedence. Between equally specific
+    /// overrides, the last one wins.
+    pub fn is_enabled_for_file(&self, kind: CairoLintKind, file_path: &str) -> bool {
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, lint_override)| lint_override.matches(file_path))
+            .filter_map(|(index, lint_override)| {
+                lint_override.lints.get(kind.name()).map(|enabled| ((lint_override.specificity(), index), *enabled))
+            })
+            .max_by_key(|(precedence, _)| *precedence)
+            .map_or_else(|| self.is_enabled(kind), |(_, enabled)| enabled)
+    }
+
+    /// Stable hash of the lints run with this configuration and of their severities. It changes
+    /// with the set of lints that are run, so CI can tell when the results may differ for another
+    /// reason than a change of the code.
+    pub fn ruleset_hash(&self) -> u64 {
+        // FNV-1a, the std hashers aren't guaranteed to be stable across releases.
+        let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+        let mut write = |text: &str| {
+            for byte in text.bytes() {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+        for kind in CairoLintKind::ALL {
+            write(&format!("{}={}:{:?};", kind.name(), self.is_enabled(kind), kind.severity()));
+        }
+        write(&format!("public-api-only={};", self.public_api_only));
+        write(&format!("large-struct-threshold={};", self.large_struct_threshold));
+        write(&format!("todo-markers={};", self.todo_markers.join(",")));
+        write(&format!("magic-number-threshold={};", self.magic_number_threshold));
+        write(&format!("ignored-magic-numbers={:?};", self.ignored_magic_numbers));
+        write(&format!("duplicate-body-min-statements={};", self.duplicate_body_min_statements));
+        write(&format!("lint-extern-call-arguments={};", self.lint_extern_call_arguments));
+        for lint_override in &self.overrides {
+            write(&format!("override={}:", lint_override.path));
+            for (name, enabled) in &lint_override.lints {
+                write(&format!("{name}={enabled},"));
+            }
+            write(";");
+        }
+        hash
+    }
+
+    /// Checks if this configuration disables every lint, which is likely a mistake. The names that
+    /// aren't the ones of lints don't count.
+    pub fn disables_all_lints(&self) -> bool {
+        !self.default && !self.listed_lints().any(|(name, enabled)| enabled && CairoLintKind::from_name(name).is_some())
+    }
+
+    /// Gets the names listed in [`Self::lints`] and in the overrides that aren't the ones of lints,
+    /// e.g. misspelled ones, each one once and sorted.
+    pub fn unknown_lints(&self) -> Vec<&str> {
+        let unknown: BTreeSet<_> =
+            self.listed_lints().map(|(name, _)| name).filter(|name| CairoLintKind::from_name(name).is_none()).collect();
+        unknown.into_iter().collect()
+    }
+
+    fn listed_lints(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.lints
+            .iter()
+            .chain(self.overrides.iter().flat_map(|lint_override| &lint_override.lints))
+            .map(|(name, enabled)| (name.as_str(), *enabled))
+    }
+}
+
+/// Part of a configuration, read from one of its sources with the same keys as a
+/// [`CairoLintConfig`], every key being optional. Merged with [`CairoLintConfig::merged`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct CairoLintConfigLayer {
+    pub public_api_only: Option<bool>,
+    pub default: Option<bool>,
+    pub lints: BTreeMap<String, bool>,
+    pub large_struct_threshold: Option<usize>,
+    pub overrides: Vec<LintOverride>,
+    pub deny_warnings: Option<bool>,
+    pub todo_markers: Option<Vec<String>>,
+    pub magic_number_threshold: Option<usize>,
+    pub ignored_magic_numbers: Option<Vec<i64>>,
+    pub duplicate_body_min_statements: Option<usize>,
+    pub lint_extern_call_arguments: Option<bool>,
+    pub max_diagnostics: Option<usize>,
+    pub base_path: Option<PathBuf>,
+}
+
+impl CairoLintConfigLayer {
+    /// Reads the `[tool.cairo-lint]` section of a Scarb manifest, which may be the one of a
+    /// virtual workspace without any package. Without the section, the layer sets no key.
+    pub fn from_manifest(manifest: &str) -> Result<Self, toml::de::Error> {
+        #[derive(Deserialize, Default)]
+        #[serde(default)]
+        struct Manifest {
+            tool: Tool,
+        }
+        #[derive(Deserialize, Default)]
+        #[serde(default)]
+        struct Tool {
+            #[serde(rename = "cairo-lint")]
+            cairo_lint: CairoLintConfigLayer,
+        }
+        Ok(toml::from_str::<Manifest>(manifest)?.tool.cairo_lint)
+    }
+}
+
+/// Lints explicitly enabled or disabled, by name, for the files whose path matches a glob.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LintOverride {
+    /// Glob matched against the end of the path of the files, whole components only, e.g.
+    /// `src/contracts/**`. `*` matches any characters but `/`, `?` a single one and `**` any
+    /// number of directories.
+    pub path: String,
+    pub lints: BTreeMap<String, bool>,
+}
+
+impl LintOverride {
+    /// Checks if the glob of this override matches a file path.
+    pub fn matches(&self, file_path: &str) -> bool {
+        let glob = components(&self.path);
+        let path = components(file_path);
+        (0..=path.len()).any(|start| matches_components(&glob, &path[start..]))
+    }
+
+    /// How specific the glob of this override is, the number of characters that aren't wildcards.
+    /// When several overrides match a file, the most specific one wins.
+    pub fn specificity(&self) -> usize {
+        self.path.chars().filter(|c| !matches!(c, '*' | '?' | '/')).count()
+    }
+}
+
+fn components(path: &str) -> Vec<&str> {
+    path.split(['/', '\\']).filter(|component| !component.is_empty()).collect()
+}
+
+fn matches_components(glob: &[&str], path: &[&str]) -> bool {
+    match glob.split_first() {
+        None => path.is_empty(),
+        Some((&"**", glob_rest)) => (0..=path.len()).any(|skipped| matches_components(glob_rest, &path[skipped..])),
+        Some((glob_component, glob_rest)) => path.split_first().is_some_and(|(component, path_rest)| {
+            let glob_chars = glob_component.chars().collect::<Vec<_>>();
+            let chars = component.chars().collect::<Vec<_>>();
+            matches_chars(&glob_chars, &chars) && matches_components(glob_rest, path_rest)
+        }),
+    }
+}
+
+fn matches_chars(glob: &[char], text: &[char]) -> bool {
+    match glob.split_first() {
+        None => text.is_empty(),
+        Some(('*', glob_rest)) => (0..=text.len()).any(|skipped| matches_chars(glob_rest, &text[skipped..])),
+        Some(('?', glob_rest)) => !text.is_empty() && matches_chars(glob_rest, &text[1..]),
+        Some((c, glob_rest)) => text.first() == Some(c) && matches_chars(glob_rest, &text[1..]),
+    }
+}
@@ -1,11 +1,39 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::iter;
+use std::ops::Range;
+use std::path::Path;
+
 use annotate_snippets::{Level, Renderer, Snippet};
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::{DiagnosticEntry, DiagnosticLocation, Severity};
 use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_utils::Upcast;
+use serde::Serialize;
+
+use crate::config::CairoLintConfig;
+use crate::fix::fix_semantic_diagnostic;
+use crate::plugin::{diagnostic_kind_from_message, CairoLintKind};
+use crate::VERSION;
 
-pub fn format_diagnostic<'a>(diagnostic: &'a SemanticDiagnostic, db: &'a RootDatabase, renderer: &Renderer) -> String {
+/// Renders a diagnostic with the source code it points to. The path of the file is relative to
+/// `base_path` when given, as described in [`relative_path`].
+pub fn format_diagnostic<'a>(
+    diagnostic: &'a SemanticDiagnostic,
+    db: &'a RootDatabase,
+    renderer: &Renderer,
+    base_path: Option<&Path>,
+) -> String {
     let location = diagnostic.location(db.upcast());
     let file_id = location.file_id;
     let span = location.span;
@@ -19,7 +47,7 @@ pub fn format_diagnostic<'a>(diagnostic: &'a SemanticDiagnostic, db: &'a RootDat
             level.title(&diagnostic.format(db)).snippet(
                 Snippet::source(db.file_content(file_id).unwrap().as_ref())
                     .line_start(file_location.start.line)
-                    .origin(&file_id.full_path(db.upcast()))
+                    .origin(&relative_path(&file_id.full_path(db.upcast()), base_path))
                     .fold(true)
                     .annotation(level.span(span.to_str_range())),
             ),
@@ -27,3 +55,427 @@ pub fn format_diagnostic<'a>(diagnostic: &'a SemanticDiagnostic, db: &'a RootDat
         .to_string();
     format!("{}\n", res)
 }
+
+/// Path of a file as written in the diagnostics: relative to the base directory when the file is
+/// inside of it, unchanged otherwise.
+pub fn relative_path(path: &str, base_path: Option<&Path>) -> String {
+    base_path
+        .and_then(|base_path| Path::new(path).strip_prefix(base_path).ok())
+        .map_or_else(|| path.to_owned(), |relative| relative.to_string_lossy().into_owned())
+}
+
+/// Where a diagnostic points in the content of its file. Lines and columns are 0-based, and the
+/// byte range is the one of the span in the content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file_path: String,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    pub byte_range: Range<usize>,
+}
+
+impl SourceLocation {
+    /// Resolves a location of the compiler, `None` when the content of its file can't be read or
+    /// is too short to contain the span.
+    pub fn new(db: &dyn SemanticGroup, location: &DiagnosticLocation) -> Option<Self> {
+        let files_db: &dyn FilesGroup = db.upcast();
+        let byte_range = location.span.to_str_range();
+        if files_db.file_content(location.file_id)?.len() < byte_range.end {
+            return None;
+        }
+        let position = location.span.position_in_file(files_db, location.file_id)?;
+        Some(Self {
+            file_path: location.file_id.full_path(files_db),
+            start: (position.start.line, position.start.col),
+            end: (position.end.line, position.end.col),
+            byte_range,
+        })
+    }
+
+    /// Resolves the pointer of a [`PluginDiagnostic`] to the span of its node without trivia, which
+    /// is the location of the diagnostic the compiler reports for it.
+    pub fn from_stable_ptr(db: &dyn SemanticGroup, stable_ptr: SyntaxStablePtrId) -> Option<Self> {
+        Self::new(db, &StableLocation::new(stable_ptr).diagnostic_location(db.upcast()))
+    }
+}
+
+/// A diagnostic as written by [`write_json`] and [`write_json_lines`]. Lines and columns are
+/// 1-based, and the file is relative to the base path given to them as in [`relative_path`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JsonDiagnostic {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    /// Either `warning` or `error`.
+    pub severity: &'static str,
+    /// Name of the lint, `None` for the diagnostics of the compiler.
+    pub lint: Option<&'static str>,
+    pub message: String,
+}
+
+impl JsonDiagnostic {
+    pub fn new(db: &RootDatabase, diagnostic: &SemanticDiagnostic, base_path: Option<&Path>) -> Self {
+        let location = diagnostic.location(db.upcast());
+        let (start, end) = SourceLocation::new(db, &location)
+            .map_or(((0, 0), (0, 0)), |source_location| (source_location.start, source_location.end));
+        let kind = lint_kind(diagnostic);
+        Self {
+            file: relative_path(&location.file_id.full_path(db.upcast()), base_path),
+            line: start.0 + 1,
+            column: start.1 + 1,
+            end_line: end.0 + 1,
+            end_column: end.1 + 1,
+            severity: match diagnostic.severity() {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            },
+            lint: (kind != CairoLintKind::Unknown).then(|| kind.name()),
+            message: diagnostic.format(db),
+        }
+    }
+}
+
+/// The linter and the lints that reported diagnostics, written once at the start of the machine
+/// readable formats, even without diagnostics, so that CI can tell when the results may change for
+/// another reason than the code. See [`CairoLintConfig::ruleset_hash`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Ruleset {
+    pub version: &'static str,
+    /// The hash as 16 hexadecimal digits, as many JSON parsers would lose precision on the number.
+    pub ruleset_hash: String,
+}
+
+impl Ruleset {
+    pub fn new(config: &CairoLintConfig) -> Self {
+        Self { version: VERSION, ruleset_hash: format!("{:016x}", config.ruleset_hash()) }
+    }
+}
+
+impl fmt::Display for Ruleset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cairo-lint {} ({})", self.version, self.ruleset_hash)
+    }
+}
+
+/// The diagnostics as written in a single JSON object, after the fields of the [`Ruleset`] they
+/// were reported with.
+#[derive(Serialize)]
+struct Report<'a, T> {
+    #[serde(flatten)]
+    ruleset: &'a Ruleset,
+    diagnostics: Vec<T>,
+}
+
+/// Writes diagnostics as a single JSON object with the fields of the [`Ruleset`] and the array of
+/// [`JsonDiagnostic`] as `diagnostics`.
+pub fn write_json(
+    db: &RootDatabase,
+    diagnostics: &[SemanticDiagnostic],
+    base_path: Option<&Path>,
+    ruleset: &Ruleset,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    let diagnostics =
+        diagnostics.iter().map(|diagnostic| JsonDiagnostic::new(db, diagnostic, base_path)).collect::<Vec<_>>();
+    serde_json::to_writer(&mut writer, &Report { ruleset, diagnostics })?;
+    writeln!(writer)
+}
+
+/// Writes diagnostics as JSON Lines, the [`Ruleset`] on the first line and then one
+/// [`JsonDiagnostic`] per line. Each diagnostic is written as soon as it's yielded, so this pairs
+/// with [`stream_crate_diagnostics`] to avoid holding the diagnostics of a whole crate in memory.
+pub fn write_json_lines(
+    db: &RootDatabase,
+    diagnostics: impl IntoIterator<Item = SemanticDiagnostic>,
+    base_path: Option<&Path>,
+    ruleset: &Ruleset,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    serde_json::to_writer(&mut writer, ruleset)?;
+    writeln!(writer)?;
+    writer.flush()?;
+    for diagnostic in diagnostics {
+        serde_json::to_writer(&mut writer, &JsonDiagnostic::new(db, &diagnostic, base_path))?;
+        writeln!(writer)?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// A diagnostic as written by [`write_review`], for the bots posting the diagnostics as inline
+/// comments of a pull request. Lines are 1-based, and the file is relative to the base path as in
+/// [`relative_path`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewDiagnostic {
+    /// Name of the lint, `None` for the diagnostics of the compiler.
+    pub rule: Option<&'static str>,
+    /// Either `warning` or `error`.
+    pub severity: &'static str,
+    pub message: String,
+    pub file: String,
+    pub line: usize,
+    pub end_line: usize,
+    /// `None` when there's no fix for the diagnostic.
+    pub suggestion: Option<ReviewSuggestion>,
+}
+
+/// The fix of a diagnostic, as the replacement of whole lines since that's what the code review
+/// tools suggest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewSuggestion {
+    pub line: usize,
+    pub end_line: usize,
+    /// New content of the lines, without the final newline.
+    pub replacement: String,
+}
+
+impl ReviewDiagnostic {
+    pub fn new(db: &RootDatabase, diagnostic: &SemanticDiagnostic, base_path: Option<&Path>) -> Self {
+        let JsonDiagnostic { file, line, end_line, severity, lint, message, .. } =
+            JsonDiagnostic::new(db, diagnostic, base_path);
+        Self { rule: lint, severity, message, file, line, end_line, suggestion: ReviewSuggestion::new(db, diagnostic) }
+    }
+}
+
+impl ReviewSuggestion {
+    fn new(db: &RootDatabase, diagnostic: &SemanticDiagnostic) -> Option<Self> {
+        let (node, fix) = fix_semantic_diagnostic(db, diagnostic)?;
+        let content = db.file_content(diagnostic.location(db.upcast()).file_id)?;
+        let fixed = node.span(db.upcast()).to_str_range();
+        // The fixed text can end with the newline of its last line, as part of its trivia.
+        let lines_start = content[..fixed.start].rfind('\n').map_or(0, |newline| newline + 1);
+        let (lines_end, rest_of_line) = if content[..fixed.end].ends_with('\n') {
+            (fixed.end - 1, "")
+        } else {
+            let lines_end = content[fixed.end..].find('\n').map_or(content.len(), |newline| fixed.end + newline);
+            (lines_end, &content[fixed.end..lines_end])
+        };
+        let line = content[..lines_start].matches('\n').count() + 1;
+        let replacement = format!("{}{fix}{rest_of_line}", &content[lines_start..fixed.start]);
+        Some(Self {
+            line,
+            end_line: line + content[lines_start..lines_end].matches('\n').count(),
+            replacement: replacement.strip_suffix('\n').unwrap_or(&replacement).to_owned(),
+        })
+    }
+}
+
+/// Writes diagnostics as a single JSON object with the fields of the [`Ruleset`] and the array of
+/// [`ReviewDiagnostic`] as `diagnostics`.
+pub fn write_review(
+    db: &RootDatabase,
+    diagnostics: &[SemanticDiagnostic],
+    base_path: Option<&Path>,
+    ruleset: &Ruleset,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    let diagnostics =
+        diagnostics.iter().map(|diagnostic| ReviewDiagnostic::new(db, diagnostic, base_path)).collect::<Vec<_>>();
+    serde_json::to_writer(&mut writer, &Report { ruleset, diagnostics })?;
+    writeln!(writer)
+}
+
+/// Outcome of a run, deciding its exit code the same way for every front-end. It's displayed as
+/// the counts of diagnostics per severity, for the front-ends only reporting those.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct LintSummary {
+    pub errors: usize,
+    pub warnings: usize,
+    /// Whether there are diagnostics while warnings are denied. Without it a run never fails
+    /// because of its diagnostics, errors included, so that the exit code only changes on request.
+    pub should_fail: bool,
+}
+
+impl LintSummary {
+    /// Summarizes the diagnostics reported with a configuration.
+    pub fn new(
+        diagnostics: impl IntoIterator<Item = impl Borrow<SemanticDiagnostic>>,
+        config: &CairoLintConfig,
+    ) -> Self {
+        let (mut errors, mut warnings) = (0, 0);
+        for diagnostic in diagnostics {
+            match diagnostic.borrow().severity() {
+                Severity::Error => errors += 1,
+                Severity::Warning => warnings += 1,
+            }
+        }
+        Self::from_counts(errors, warnings, config.deny_warnings)
+    }
+
+    pub fn from_counts(errors: usize, warnings: usize, deny_warnings: bool) -> Self {
+        Self { errors, warnings, should_fail: deny_warnings && errors + warnings > 0 }
+    }
+
+    /// Combines the summaries of several runs, e.g. of packages with different configurations.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            errors: self.errors + other.errors,
+            warnings: self.warnings + other.warnings,
+            should_fail: self.should_fail || other.should_fail,
+        }
+    }
+}
+
+impl fmt::Display for LintSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        write!(f, "{} error{}, {} warning{}", self.errors, plural(self.errors), self.warnings, plural(self.warnings))
+    }
+}
+
+/// When to color the rendered diagnostics, the errors being red and the warnings yellow.
+#[cfg(feature = "color")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color the diagnostics when printing them to a terminal, unless `NO_COLOR` is set.
+    /// `CLICOLOR_FORCE` forces the colors.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+#[cfg(feature = "color")]
+impl ColorChoice {
+    pub fn should_color(self) -> bool {
+        use std::io::IsTerminal;
+
+        let is_set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty() && value != "0");
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) => false,
+            ColorChoice::Auto => is_set("CLICOLOR_FORCE") || std::io::stdout().is_terminal(),
+        }
+    }
+
+    /// Gets the renderer to pass to [`format_diagnostic`].
+    pub fn renderer(self) -> Renderer {
+        if self.should_color() { Renderer::styled() } else { Renderer::plain() }
+    }
+}
+
+/// Gets the diagnostics of all the modules of a crate, in the canonical order described in
+/// [`canonicalize_diagnostics`].
+pub fn crate_diagnostics(db: &RootDatabase, crate_id: CrateId) -> Vec<SemanticDiagnostic> {
+    let diagnostics = db
+        .crate_modules(crate_id)
+        .iter()
+        .filter_map(|module_id| db.module_semantic_diagnostics(*module_id).ok())
+        .flat_map(|module_diags| module_diags.get_all())
+        .collect();
+    canonicalize_diagnostics(db, diagnostics)
+}
+
+/// Gets the diagnostics of all the modules of a crate lazily, one module after the other. The
+/// diagnostics of each module are in the canonical order described in [`canonicalize_diagnostics`].
+pub fn stream_crate_diagnostics(db: &RootDatabase, crate_id: CrateId) -> impl Iterator<Item = SemanticDiagnostic> + '_ {
+    db.crate_modules(crate_id).to_vec().into_iter().flat_map(move |module_id| {
+        let diagnostics = db.module_semantic_diagnostics(module_id).map(|diags| diags.get_all()).unwrap_or_default();
+        canonicalize_diagnostics(db, diagnostics)
+    })
+}
+
+/// Message of the diagnostic replacing the ones past the limit given to [`limit_diagnostics`].
+pub fn truncation_notice(omitted: usize) -> String {
+    let omitted = if omitted == 1 {
+        "1 more diagnostic wasn't".to_owned()
+    } else {
+        format!("{omitted} more diagnostics weren't")
+    };
+    format!("{omitted} reported, starting with this one. Raise `max-diagnostics` to see them.")
+}
+
+/// Keeps the first `max_diagnostics` diagnostics and replaces the others with a single diagnostic
+/// telling how many were left out, located at the first one left out. It's an error if any of them
+/// is, so that the errors left out still show. Everything is kept when there is no limit.
+///
+/// The diagnostics are expected in the canonical order described in [`canonicalize_diagnostics`],
+/// so that the earliest ones are kept.
+pub fn limit_diagnostics(
+    diagnostics: impl IntoIterator<Item = SemanticDiagnostic>,
+    max_diagnostics: Option<usize>,
+) -> impl Iterator<Item = SemanticDiagnostic> {
+    let mut diagnostics = diagnostics.into_iter();
+    let mut remaining = max_diagnostics.unwrap_or(usize::MAX);
+    let mut truncated = false;
+    iter::from_fn(move || {
+        if truncated {
+            return None;
+        }
+        let diagnostic = diagnostics.next()?;
+        if remaining > 0 {
+            remaining -= 1;
+            return Some(diagnostic);
+        }
+        truncated = true;
+        let stable_ptr = diagnostic.stable_location.stable_ptr();
+        let (mut omitted, mut severity) = (1, diagnostic.severity());
+        for diagnostic in diagnostics.by_ref() {
+            omitted += 1;
+            if diagnostic.severity() == Severity::Error {
+                severity = Severity::Error;
+            }
+        }
+        let notice = PluginDiagnostic { stable_ptr, message: truncation_notice(omitted), severity };
+        Some(SemanticDiagnostic::new(StableLocation::new(stable_ptr), SemanticDiagnosticKind::PluginDiagnostic(notice)))
+    })
+}
+
+/// Gets the diagnostics of all the modules of a crate grouped by lint kind, diagnostics that don't
+/// come from the linter being in the [`CairoLintKind::Unknown`] group.
+pub fn crate_diagnostics_by_kind(
+    db: &RootDatabase,
+    crate_id: CrateId,
+) -> HashMap<CairoLintKind, Vec<SemanticDiagnostic>> {
+    group_diagnostics_by_kind(db, crate_diagnostics(db, crate_id))
+}
+
+/// Groups diagnostics by lint kind. Each group is in the canonical order described in
+/// [`canonicalize_diagnostics`].
+pub fn group_diagnostics_by_kind(
+    db: &RootDatabase,
+    diagnostics: Vec<SemanticDiagnostic>,
+) -> HashMap<CairoLintKind, Vec<SemanticDiagnostic>> {
+    let mut groups: HashMap<CairoLintKind, Vec<SemanticDiagnostic>> = HashMap::new();
+    for diagnostic in canonicalize_diagnostics(db, diagnostics) {
+        groups.entry(lint_kind(&diagnostic)).or_default().push(diagnostic);
+    }
+    groups
+}
+
+/// Sorts diagnostics by file, start offset, end offset and kind, and removes the exact duplicates
+/// (same kind, message and span).
+///
+/// This makes the output independent of the order in which the diagnostics were produced.
+pub fn canonicalize_diagnostics(db: &RootDatabase, diagnostics: Vec<SemanticDiagnostic>) -> Vec<SemanticDiagnostic> {
+    let mut keyed_diagnostics = diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            let location = diagnostic.location(db.upcast());
+            let range = location.span.to_str_range();
+            let key = (
+                location.file_id.full_path(db.upcast()),
+                range.start,
+                range.end,
+                lint_kind(&diagnostic),
+                diagnostic.format(db),
+            );
+            (key, diagnostic)
+        })
+        .collect::<Vec<_>>();
+    keyed_diagnostics.sort_by(|(first, _), (second, _)| first.cmp(second));
+    keyed_diagnostics.dedup_by(|(first, _), (second, _)| first == second);
+    keyed_diagnostics.into_iter().map(|(_, diagnostic)| diagnostic).collect()
+}
+
+/// Gets the lint kind of a diagnostic. Diagnostics that don't come from the linter are of the
+/// [`CairoLintKind::Unknown`] kind.
+fn lint_kind(diagnostic: &SemanticDiagnostic) -> CairoLintKind {
+    match &diagnostic.kind {
+        SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => diagnostic_kind_from_message(&plugin_diag.message),
+        _ => CairoLintKind::Unknown,
+    }
+}
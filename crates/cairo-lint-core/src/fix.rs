@@ -3,7 +3,10 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
-use cairo_lang_syntax::node::ast::{Expr, ExprBinary, ExprMatch, Pattern};
+use cairo_lang_syntax::node::ast::{
+    BlockOrIf, Expr, ExprBinary, ExprIf, ExprMatch, OptionElseClause, OptionExprClause, OptionTerminalSemicolon,
+    Pattern, StatementExpr, StatementReturn,
+};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
 use cairo_lang_utils::Upcast;
@@ -11,7 +14,9 @@ use log::debug;
 
 use crate::lints::bool_comparison::generate_fixed_text_for_comparison;
 use crate::lints::double_comparison;
+use crate::lints::ifs::single_assignment;
 use crate::lints::single_match::is_expr_unit;
+use crate::lints::yoda_comparison::swapped_operator;
 use crate::plugin::{diagnostic_kind_from_message, CairoLintKind};
 
 mod import_fixes;
@@ -152,6 +157,27 @@ impl Fixer {
                 self.fix_double_comparison(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
             }
             CairoLintKind::BreakUnit => self.fix_break_unit(db, plugin_diag.stable_ptr.lookup(db.upcast())),
+            CairoLintKind::RedundantBranchInto => {
+                self.fix_redundant_into(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::IfAssignments => {
+                self.fix_if_assignments(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::DefaultComparison => {
+                self.fix_default_comparison(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::NeedlessReturnBlock => {
+                self.fix_needless_return_block(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::NeedlessUnitReturn => {
+                self.fix_needless_unit_return(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::YodaComparison => {
+                self.fix_yoda_comparison(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::RedundantSnapshot => {
+                self.fix_redundant_snapshot(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
             CairoLintKind::BoolComparison => self.fix_bool_comparison(
                 db,
                 ExprBinary::from_syntax_node(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast())),
@@ -166,6 +192,209 @@ impl Fixer {
         node.get_text(db).replace("break ();", "break;").to_string()
     }
 
+    /// Removes a redundant `.into()` call, keeping only the converted value.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the `value.into()` method call.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the method call replaced by its receiver.
+    pub fn fix_redundant_into(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let text = node.get_text(db);
+        let Expr::Binary(method_call) = Expr::from_syntax_node(db, node.clone()) else {
+            return text;
+        };
+        text.replace(
+            &node.get_text_without_trivia(db),
+            &method_call.lhs(db).as_syntax_node().get_text_without_trivia(db),
+        )
+    }
+
+    /// Turns an `if` whose branches only assign the same variable into the assignment of an `if`
+    /// expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the `if` expression.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the assignment of the `if` expression.
+    ///
+    /// # Example
+    ///
+    /// Input: `if c { x = 1; } else { x = 2; }`
+    /// Output: `x = if c { 1 } else { 2 };`
+    pub fn fix_if_assignments(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let text = node.get_text(db);
+        let if_expr = ExprIf::from_syntax_node(db, node.clone());
+        let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+            return text;
+        };
+        let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+            return text;
+        };
+        let (Some(if_assignment), Some(else_assignment)) =
+            (single_assignment(db, &if_expr.if_block(db)), single_assignment(db, &else_block))
+        else {
+            return text;
+        };
+        // The `if` used to be a statement on its own, it may already be followed by a semicolon.
+        let has_semicolon = node.parent().is_some_and(|statement| {
+            matches!(
+                StatementExpr::from_syntax_node(db, statement).semicolon(db),
+                OptionTerminalSemicolon::TerminalSemicolon(_)
+            )
+        });
+        let value = |assignment: &ExprBinary| assignment.rhs(db).as_syntax_node().get_text_without_trivia(db);
+        text.replace(
+            &node.get_text_without_trivia(db),
+            &format!(
+                "{} = if {} {{ {} }} else {{ {} }}{}",
+                if_assignment.lhs(db).as_syntax_node().get_text_without_trivia(db),
+                if_expr.condition(db).as_syntax_node().get_text_without_trivia(db),
+                value(&if_assignment),
+                value(&else_assignment),
+                if has_semicolon { "" } else { ";" },
+            ),
+        )
+    }
+
+    /// Replaces the `Default::default()` operands of a comparison with `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the comparison.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the comparison against `0`.
+    ///
+    /// # Example
+    ///
+    /// Input: `x == Default::default()`
+    /// Output: `x == 0`
+    pub fn fix_default_comparison(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let binary_expr = ExprBinary::from_syntax_node(db, node.clone());
+        let mut text = node.get_text(db);
+        let node_start = node.span(db).to_str_range().start;
+        // From the right so that the replacement of an operand doesn't move the other one.
+        for operand in [binary_expr.rhs(db), binary_expr.lhs(db)] {
+            // The lint only fires for the default of numeric types, whatever the path to it.
+            let is_default = matches!(
+                &operand,
+                Expr::FunctionCall(call) if call.path(db).as_syntax_node().get_text_without_trivia(db).ends_with("::default")
+            );
+            if is_default {
+                let range = operand.as_syntax_node().span_without_trivia(db).to_str_range();
+                text.replace_range(range.start - node_start..range.end - node_start, "0");
+            }
+        }
+        text
+    }
+
+    /// Turns the `return` of a block at the end of a function into the tail expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the `return` statement.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the block alone, the original indentation being kept.
+    ///
+    /// # Example
+    ///
+    /// Input: `return { let x = 1; x + 1 };`
+    /// Output: `{ let x = 1; x + 1 }`
+    pub fn fix_needless_return_block(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let return_statement = StatementReturn::from_syntax_node(db, node.clone());
+        let OptionExprClause::ExprClause(expr_clause) = return_statement.expr_clause(db) else {
+            return node.get_text(db);
+        };
+        node.get_text(db).replace(
+            &node.get_text_without_trivia(db),
+            &expr_clause.expr(db).as_syntax_node().get_text_without_trivia(db),
+        )
+    }
+
+    /// Removes a bare `return;` ending a function returning `()` with its line, keeping the
+    /// comments above it.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the `return` statement.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the lines of comments preceding the statement, if any.
+    pub fn fix_needless_unit_return(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let mut leading_trivia_span = node.span(db);
+        leading_trivia_span.end = node.span_start_without_trivia(db);
+        node.get_text_of_span(db, leading_trivia_span).trim_end_matches(' ').to_string()
+    }
+
+    /// Removes an `@` taken of a value that is already a snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the redundant snapshot.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the snapshotted value alone.
+    ///
+    /// # Example
+    ///
+    /// Input: `@@x`
+    /// Output: `@x`
+    pub fn fix_redundant_snapshot(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let text = node.get_text(db);
+        let Expr::Unary(snapshot) = Expr::from_syntax_node(db, node.clone()) else {
+            return text;
+        };
+        text.replace(&node.get_text_without_trivia(db), &snapshot.expr(db).as_syntax_node().get_text_without_trivia(db))
+    }
+
+    /// Swaps the operands of a comparison with the constant on the left, adapting the operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
+    /// * `node` - The `SyntaxNode` of the comparison.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the comparison having the constant on the right.
+    ///
+    /// # Example
+    ///
+    /// Input: `5 < x`
+    /// Output: `x > 5`
+    pub fn fix_yoda_comparison(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+        let text = node.get_text(db);
+        let binary_expr = ExprBinary::from_syntax_node(db, node.clone());
+        let Some(operator) = swapped_operator(&binary_expr.op(db)) else {
+            return text;
+        };
+        text.replace(
+            &node.get_text_without_trivia(db),
+            &format!(
+                "{} {operator} {}",
+                binary_expr.rhs(db).as_syntax_node().get_text_without_trivia(db),
+                binary_expr.lhs(db).as_syntax_node().get_text_without_trivia(db),
+            ),
+        )
+    }
+
     pub fn fix_bool_comparison(&self, db: &dyn SyntaxGroup, node: ExprBinary) -> String {
         let lhs = node.lhs(db).as_syntax_node().get_text(db);
         let rhs = node.rhs(db).as_syntax_node().get_text(db);
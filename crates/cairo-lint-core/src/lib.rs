@@ -1,5 +1,11 @@
 #![feature(let_chains)]
+pub mod config;
 pub mod diagnostics;
 pub mod fix;
 pub mod lints;
 pub mod plugin;
+pub mod profiling;
+pub mod suppressions;
+
+/// Version of the linter.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
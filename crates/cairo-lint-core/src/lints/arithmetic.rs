@@ -0,0 +1,40 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, VarId};
+
+pub const UNCHECKED_ARITHMETIC: &str = "Arithmetic on function parameters panics if it overflows. Consider using \
+                                        `checked_add`/`checked_mul` to handle the overflow.";
+
+const ARITHMETIC_SUFFIXES: [&str; 2] = ["::add\"", "::mul\""];
+const UNSIGNED_TYPES: [&str; 5] =
+    ["core::integer::u8", "core::integer::u16", "core::integer::u32", "core::integer::u64", "core::integer::u128"];
+
+pub fn check_unchecked_arithmetic(
+    db: &dyn SemanticGroup,
+    func_call: &ExprFunctionCall,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let name = func_call.function.name(db);
+    if !ARITHMETIC_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+        || !UNSIGNED_TYPES.contains(&func_call.ty.format(db).as_str())
+    {
+        return;
+    }
+    // Parameters are the values the callers control, only flag operations between two of them.
+    if let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] = func_call.args.as_slice()
+        && is_parameter(&arenas.exprs[*lhs])
+        && is_parameter(&arenas.exprs[*rhs])
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: func_call.stable_ptr.into(),
+            message: UNCHECKED_ARITHMETIC.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+fn is_parameter(expr: &Expr) -> bool {
+    matches!(expr, Expr::Var(expr_var) if matches!(expr_var.var, VarId::Param(_)))
+}
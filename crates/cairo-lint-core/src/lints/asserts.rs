@@ -0,0 +1,43 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{ExprInlineMacro, WrappedArgList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const ASSERT_WITHOUT_MESSAGE: &str =
+    "This assertion has no message. Consider adding one describing the failure to make it easier to debug.";
+
+/// Assertion macros with the number of arguments they take before their optional message.
+const ASSERT_MACROS: [(&str, usize); 7] = [
+    ("assert", 1),
+    ("assert_eq", 2),
+    ("assert_ne", 2),
+    ("assert_lt", 2),
+    ("assert_le", 2),
+    ("assert_gt", 2),
+    ("assert_ge", 2),
+];
+
+pub fn check_assert_without_message(
+    db: &dyn SyntaxGroup,
+    inline_macro: &ExprInlineMacro,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let name = inline_macro.path(db).as_syntax_node().get_text_without_trivia(db);
+    let Some((_, required_arguments)) = ASSERT_MACROS.iter().find(|(macro_name, _)| *macro_name == name) else {
+        return;
+    };
+    let arguments = match inline_macro.arguments(db) {
+        WrappedArgList::ParenthesizedArgList(arguments) => arguments.arguments(db),
+        WrappedArgList::BracketedArgList(arguments) => arguments.arguments(db),
+        WrappedArgList::BracedArgList(arguments) => arguments.arguments(db),
+        WrappedArgList::Missing(_) => return,
+    };
+    if arguments.elements(db).len() == *required_arguments {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: inline_macro.stable_ptr().untyped(),
+            message: ASSERT_WITHOUT_MESSAGE.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
@@ -0,0 +1,171 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, Expr, ExprBinary, ExprBlock, Modifier, OptionTypeClause, Pattern, PatternIdentifier, Statement,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+pub const SINGLE_REASSIGNMENT: &str = "This mutable variable is reassigned once before it's ever used. Consider \
+                                       declaring it with its final value instead.";
+pub const REDUNDANT_CHAIN_BINDING: &str =
+    "This `Option`/`Result` is only bound to be used once, right after. Consider chaining the calls instead.";
+
+/// Methods of `Option` and `Result` that give back another `Option` or `Result`. The corelib has no
+/// closures, so its combinators are the ones taking values.
+const COMBINATORS: [&str; 3] = ["ok", "err", "ok_or"];
+/// Length past which a chain is considered more readable when split with a binding.
+const MAX_CHAIN_LENGTH: usize = 80;
+
+pub fn check_single_reassignment(db: &dyn SyntaxGroup, block: &ExprBlock, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let statements = block.statements(db).elements(db);
+    for (index, statement) in statements.iter().enumerate() {
+        let Statement::Let(let_statement) = statement else {
+            continue;
+        };
+        let Pattern::Identifier(identifier) = let_statement.pattern(db) else {
+            continue;
+        };
+        let is_mut = identifier.modifiers(db).elements(db).iter().any(|modifier| matches!(modifier, Modifier::Mut(_)));
+        // Dropping the initial value must not drop any side effect.
+        if !is_mut || has_calls(db, &let_statement.rhs(db).as_syntax_node()) {
+            continue;
+        }
+        let name = identifier.name(db).text(db).to_string();
+        let mut following = statements[index + 1..].iter();
+        let Some(first_use) = following.find(|statement| mentions(db, &statement.as_syntax_node(), &name)) else {
+            continue;
+        };
+        if is_reassignment(db, first_use, &name)
+            && !following.any(|statement| mutates(db, &statement.as_syntax_node(), &name))
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: let_statement.stable_ptr().untyped(),
+                message: SINGLE_REASSIGNMENT.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+pub fn check_redundant_chain_binding(db: &dyn SyntaxGroup, block: &ExprBlock, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let statements = block.statements(db).elements(db);
+    for (index, statement) in statements.iter().enumerate() {
+        let Statement::Let(let_statement) = statement else {
+            continue;
+        };
+        let Pattern::Identifier(identifier) = let_statement.pattern(db) else {
+            continue;
+        };
+        // A type annotation may be needed for the chain to be inferred.
+        if !identifier.modifiers(db).elements(db).is_empty()
+            || !matches!(let_statement.type_clause(db), OptionTypeClause::Empty(_))
+        {
+            continue;
+        }
+        let rhs = let_statement.rhs(db);
+        if !is_method_call(db, &rhs, |name| COMBINATORS.contains(&name)) {
+            continue;
+        }
+        let Some(next_statement) = statements.get(index + 1) else {
+            continue;
+        };
+        let name = identifier.name(db).text(db).to_string();
+        let next_node = next_statement.as_syntax_node();
+        let uses = next_node
+            .descendants(db)
+            .filter(|node| node.kind(db) == SyntaxKind::ExprPath && node.get_text_without_trivia(db) == name)
+            .count();
+        let Some(use_expr) = next_node.descendants(db).find_map(|node| receiver_call(db, node, &name)) else {
+            continue;
+        };
+        let chain_length = rhs.as_syntax_node().get_text_without_trivia(db).len()
+            + use_expr.as_syntax_node().get_text_without_trivia(db).len()
+            - name.len();
+        if uses == 1
+            && chain_length <= MAX_CHAIN_LENGTH
+            && !statements[index + 2..].iter().any(|statement| mentions(db, &statement.as_syntax_node(), &name))
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: let_statement.stable_ptr().untyped(),
+                message: REDUNDANT_CHAIN_BINDING.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Checks if an expression is a method call of one of the accepted methods.
+fn is_method_call(db: &dyn SyntaxGroup, expr: &Expr, is_accepted: impl Fn(&str) -> bool) -> bool {
+    if let Expr::Binary(binary_expr) = expr
+        && let BinaryOperator::Dot(_) = binary_expr.op(db)
+        && let Expr::FunctionCall(call) = binary_expr.rhs(db)
+    {
+        is_accepted(&call.path(db).as_syntax_node().get_text_without_trivia(db))
+    } else {
+        false
+    }
+}
+
+/// Gets the method call of a node, if its receiver is the given variable.
+fn receiver_call(db: &dyn SyntaxGroup, node: SyntaxNode, name: &str) -> Option<Expr> {
+    if node.kind(db) != SyntaxKind::ExprBinary {
+        return None;
+    }
+    let expr = Expr::from_syntax_node(db, node);
+    let Expr::Binary(binary_expr) = &expr else {
+        return None;
+    };
+    let is_receiver = binary_expr.lhs(db).as_syntax_node().get_text_without_trivia(db) == name;
+    (is_receiver && is_method_call(db, &expr, |_| true)).then_some(expr)
+}
+
+fn has_calls(db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    node.descendants(db).any(|node| matches!(node.kind(db), SyntaxKind::ExprFunctionCall | SyntaxKind::ExprInlineMacro))
+}
+
+/// Checks if a node reads, writes or shadows a variable.
+fn mentions(db: &dyn SyntaxGroup, node: &SyntaxNode, name: &str) -> bool {
+    node.descendants(db).any(|node| match node.kind(db) {
+        SyntaxKind::ExprPath => node.get_text_without_trivia(db) == name,
+        SyntaxKind::PatternIdentifier => PatternIdentifier::from_syntax_node(db, node).name(db).text(db) == name,
+        _ => false,
+    })
+}
+
+/// Checks if a statement is `name = value;` with a value that doesn't depend on the variable.
+fn is_reassignment(db: &dyn SyntaxGroup, statement: &Statement, name: &str) -> bool {
+    if let Statement::Expr(statement_expr) = statement
+        && let Expr::Binary(binary_expr) = statement_expr.expr(db)
+        && let BinaryOperator::Eq(_) = binary_expr.op(db)
+    {
+        binary_expr.lhs(db).as_syntax_node().get_text_without_trivia(db) == name
+            && !mentions(db, &binary_expr.rhs(db).as_syntax_node(), name)
+    } else {
+        false
+    }
+}
+
+/// Checks if a node may modify a variable: assigning it, calling a method on it, or passing it by
+/// reference.
+pub fn mutates(db: &dyn SyntaxGroup, node: &SyntaxNode, name: &str) -> bool {
+    node.descendants(db).any(|node| match node.kind(db) {
+        SyntaxKind::ExprBinary => {
+            let binary_expr = ExprBinary::from_syntax_node(db, node);
+            let is_mutating_op = matches!(
+                binary_expr.op(db),
+                BinaryOperator::Eq(_)
+                    | BinaryOperator::PlusEq(_)
+                    | BinaryOperator::MinusEq(_)
+                    | BinaryOperator::MulEq(_)
+                    | BinaryOperator::DivEq(_)
+                    | BinaryOperator::ModEq(_)
+                    | BinaryOperator::Dot(_)
+            );
+            is_mutating_op && binary_expr.lhs(db).as_syntax_node().get_text_without_trivia(db) == name
+        }
+        SyntaxKind::Arg => node.get_text_without_trivia(db) == format!("ref {name}"),
+        _ => false,
+    })
+}
@@ -0,0 +1,68 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+use num_bigint::BigInt;
+
+use crate::lints::conversions::{integer_range, INTO_SUFFIX};
+use crate::lints::flags::unsnapshot;
+
+pub const CONSTANT_COMPARISON: &str = "This comparison always has the same result, the compared value can't be on the \
+                                       other side of the bound. Consider removing it or fixing the bound.";
+
+/// Ordering comparisons by the suffix of their function name.
+const ORDERINGS: [(&str, fn(&BigInt, &BigInt) -> bool); 4] =
+    [("::lt\"", BigInt::lt), ("::le\"", BigInt::le), ("::gt\"", BigInt::gt), ("::ge\"", BigInt::ge)];
+
+pub fn check_constant_comparison(
+    db: &dyn SemanticGroup,
+    func_call: &ExprFunctionCall,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    if comparison_result(db, func_call, arenas).is_some() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: func_call.stable_ptr.into(),
+            message: CONSTANT_COMPARISON.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Evaluates an ordering comparison of a value with a literal bound from the range of values of
+/// the value, `None` when it depends on the value. A value converted with `into` has the range of
+/// its type before the conversion.
+pub fn comparison_result(db: &dyn SemanticGroup, func_call: &ExprFunctionCall, arenas: &Arenas) -> Option<bool> {
+    let name = func_call.function.name(db);
+    let (_, compare) = ORDERINGS.iter().find(|(suffix, _)| name.ends_with(suffix))?;
+    let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] = func_call.args.as_slice() else {
+        return None;
+    };
+    let (value, bound, is_bound_on_left) =
+        match (&arenas.exprs[unsnapshot(*lhs, arenas)], &arenas.exprs[unsnapshot(*rhs, arenas)]) {
+            (_, Expr::Literal(bound)) => (*lhs, &bound.value, false),
+            (Expr::Literal(bound), _) => (*rhs, &bound.value, true),
+            _ => return None,
+        };
+    let (min, max) = value_range(db, value, arenas)?;
+    let compare_to_bound =
+        |extreme: &BigInt| if is_bound_on_left { compare(bound, extreme) } else { compare(extreme, bound) };
+    // The result can only change once over the range, so it's always the same when it's the same at
+    // both ends.
+    let result = compare_to_bound(&min);
+    (result == compare_to_bound(&max)).then_some(result)
+}
+
+/// Gets the inclusive range of the values an integer expression can have from its type, or the
+/// type it was converted from.
+fn value_range(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> Option<(BigInt, BigInt)> {
+    let expr = &arenas.exprs[unsnapshot(expr_id, arenas)];
+    if let Expr::FunctionCall(into_call) = expr
+        && into_call.function.name(db).ends_with(INTO_SUFFIX)
+        && let [ExprFunctionCallArg::Value(converted)] = into_call.args.as_slice()
+        && let Some(range) = integer_range(&arenas.exprs[*converted].ty().format(db))
+    {
+        return Some(range);
+    }
+    integer_range(&expr.ty().format(db))
+}
@@ -0,0 +1,33 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, StatementExpr};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+pub const DISCARDED_COLLECTION: &str =
+    "The collection returned by this call is dropped without being used. Consider using it or removing the call.";
+
+/// Types of the collections that are rarely built only to be dropped.
+const COLLECTION_TYPE_PREFIXES: [&str; 4] =
+    ["core::array::Array::<", "core::array::Span::<", "core::dict::Felt252Dict::<", "core::byte_array::ByteArray"];
+
+/// Reports the calls returning a collection used as statements, `let _ = ...;` being the way to
+/// drop the result on purpose.
+pub fn check_discarded_collection(
+    db: &dyn SemanticGroup,
+    statement: &StatementExpr,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let Expr::FunctionCall(func_call) = &arenas.exprs[statement.expr] else {
+        return;
+    };
+    let return_type = func_call.ty.format(db);
+    if COLLECTION_TYPE_PREFIXES.iter().any(|prefix| return_type.starts_with(prefix)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: func_call.stable_ptr.untyped(),
+            message: DISCARDED_COLLECTION.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
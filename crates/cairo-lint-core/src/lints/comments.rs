@@ -0,0 +1,35 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+
+pub const TODO_COMMENT: &str = "This comment marks unfinished work. Consider addressing it before merging.";
+
+const COMMENT_KINDS: [SyntaxKind; 3] = [
+    SyntaxKind::TokenSingleLineComment,
+    SyntaxKind::TokenSingleLineDocComment,
+    SyntaxKind::TokenSingleLineInnerComment,
+];
+
+/// Reports the comments of a node and its descendants containing one of the markers as a word, e.g.
+/// `// TODO: handle overflows` for the `TODO` marker.
+pub fn check_todo_comments(
+    db: &dyn SyntaxGroup,
+    node: &SyntaxNode,
+    markers: &[String],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    // The comments are in the trivia of the terminals, which are descendants like any other node.
+    for comment in node.descendants(db).filter(|node| COMMENT_KINDS.contains(&node.kind(db))) {
+        let text = comment.get_text(db);
+        let mut words = text.split(|c: char| !c.is_alphanumeric() && c != '_');
+        if words.any(|word| markers.iter().any(|marker| marker == word)) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: comment.stable_ptr(),
+                message: TODO_COMMENT.to_owned(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
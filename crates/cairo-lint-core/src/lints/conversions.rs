@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+use std::iter;
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprIf};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+use num_bigint::BigInt;
+
+pub const REDUNDANT_BRANCH_INTO: &str =
+    "This value already has the type of the `if` expression. Consider removing the redundant `.into()`.";
+
+/// Followed by the literal of the target type to write instead.
+pub const IN_RANGE_LITERAL_TRY_INTO: &str = "This literal always fits in the target type.";
+
+pub const INTO_SUFFIX: &str = "::into\"";
+const TRY_INTO_SUFFIX: &str = "::try_into\"";
+const UNWRAP_SUFFIX: &str = "::unwrap\"";
+
+pub fn check_redundant_branch_into(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let Some(else_block) = if_expr.else_block else {
+        return;
+    };
+    let (Some(if_into), Some(else_into)) =
+        (tail_into_call(db, if_expr.if_block, arenas), tail_into_call(db, else_block, arenas))
+    else {
+        return;
+    };
+    for into_call in [if_into, else_into] {
+        if is_identity_conversion(into_call, arenas) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: into_call.stable_ptr.into(),
+                message: REDUNDANT_BRANCH_INTO.to_owned(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Gets the `into` call a block ends with, if any.
+fn tail_into_call<'a>(db: &dyn SemanticGroup, block: ExprId, arenas: &'a Arenas) -> Option<&'a ExprFunctionCall> {
+    let Expr::Block(expr_block) = &arenas.exprs[block] else {
+        return None;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[expr_block.tail?] else {
+        return None;
+    };
+    func_call.function.name(db).ends_with(INTO_SUFFIX).then_some(func_call)
+}
+
+/// Checks if a conversion returns a value of the type it was given.
+pub fn is_identity_conversion(func_call: &ExprFunctionCall, arenas: &Arenas) -> bool {
+    matches!(func_call.args.as_slice(), [ExprFunctionCallArg::Value(arg)] if arenas.exprs[*arg].ty() == func_call.ty)
+}
+
+pub fn check_in_range_literal_try_into(
+    db: &dyn SemanticGroup,
+    unwrap_call: &ExprFunctionCall,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    if !unwrap_call.function.name(db).ends_with(UNWRAP_SUFFIX) {
+        return;
+    }
+    let [ExprFunctionCallArg::Value(unwrapped)] = unwrap_call.args.as_slice() else {
+        return;
+    };
+    let Expr::FunctionCall(try_into_call) = &arenas.exprs[*unwrapped] else {
+        return;
+    };
+    if !try_into_call.function.name(db).ends_with(TRY_INTO_SUFFIX) {
+        return;
+    }
+    let [ExprFunctionCallArg::Value(converted)] = try_into_call.args.as_slice() else {
+        return;
+    };
+    let target_type = unwrap_call.ty.format(db);
+    if let Expr::Literal(literal) = &arenas.exprs[*converted]
+        && let Some((min, max)) = integer_range(&target_type)
+        && min <= literal.value
+        && literal.value <= max
+    {
+        // The range is only known for the integer types of the corelib.
+        let type_name = target_type.trim_start_matches("core::integer::");
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: unwrap_call.stable_ptr.into(),
+            message: format!(
+                "{IN_RANGE_LITERAL_TRY_INTO} Consider writing `{}_{type_name}` instead of using \
+                 `.try_into().unwrap()`.",
+                literal.value
+            ),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the arguments of the calls to `extern` functions of a function body, the libfuncs of the
+/// corelib among them. Their conversions are often there to give the exact type the libfunc takes.
+pub fn extern_call_arguments(db: &dyn SemanticGroup, arenas: &Arenas) -> HashSet<SyntaxStablePtrId> {
+    arenas
+        .exprs
+        .iter()
+        .filter_map(|(_, expr)| match expr {
+            Expr::FunctionCall(func_call)
+                if matches!(func_call.function.get_concrete(db).generic_function, GenericFunctionId::Extern(_)) =>
+            {
+                Some(func_call)
+            }
+            _ => None,
+        })
+        .flat_map(|func_call| &func_call.args)
+        .filter_map(|arg| match arg {
+            ExprFunctionCallArg::Value(arg) => Some(arenas.exprs[*arg].stable_ptr().untyped()),
+            ExprFunctionCallArg::Reference(_) => None,
+        })
+        .collect()
+}
+
+/// Checks if a diagnostic is in one of the given arguments of `extern` function calls.
+pub fn is_in_extern_call_argument(
+    db: &dyn SyntaxGroup,
+    diagnostic: &PluginDiagnostic,
+    arguments: &HashSet<SyntaxStablePtrId>,
+) -> bool {
+    iter::successors(Some(diagnostic.stable_ptr.lookup(db)), |node| node.parent())
+        .any(|node| arguments.contains(&node.stable_ptr()))
+}
+
+/// Gets the inclusive range of values of an integer type from its full path.
+pub fn integer_range(type_path: &str) -> Option<(BigInt, BigInt)> {
+    let range = match type_path.strip_prefix("core::integer::")? {
+        "u8" => (BigInt::from(u8::MIN), BigInt::from(u8::MAX)),
+        "u16" => (BigInt::from(u16::MIN), BigInt::from(u16::MAX)),
+        "u32" => (BigInt::from(u32::MIN), BigInt::from(u32::MAX)),
+        "u64" => (BigInt::from(u64::MIN), BigInt::from(u64::MAX)),
+        "u128" => (BigInt::from(u128::MIN), BigInt::from(u128::MAX)),
+        "i8" => (BigInt::from(i8::MIN), BigInt::from(i8::MAX)),
+        "i16" => (BigInt::from(i16::MIN), BigInt::from(i16::MAX)),
+        "i32" => (BigInt::from(i32::MIN), BigInt::from(i32::MAX)),
+        "i64" => (BigInt::from(i64::MIN), BigInt::from(i64::MAX)),
+        "i128" => (BigInt::from(i128::MIN), BigInt::from(i128::MAX)),
+        _ => return None,
+    };
+    Some(range)
+}
@@ -0,0 +1,61 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+
+pub const DEFAULT_COMPARISON: &str =
+    "Comparing with `Default::default()`, which is `0` for this type. Consider comparing with `0` instead.";
+
+/// Suffixes of the names of the comparison functions of `PartialEq` and `PartialOrd`.
+pub const COMPARISON_SUFFIXES: [&str; 6] = ["::eq\"", "::ne\"", "::lt\"", "::gt\"", "::le\"", "::ge\""];
+/// Types whose default value is the literal `0`.
+const NUMERIC_TYPES: [&str; 12] = [
+    "core::felt252",
+    "core::integer::u8",
+    "core::integer::u16",
+    "core::integer::u32",
+    "core::integer::u64",
+    "core::integer::u128",
+    "core::integer::u256",
+    "core::integer::i8",
+    "core::integer::i16",
+    "core::integer::i32",
+    "core::integer::i64",
+    "core::integer::i128",
+];
+
+pub fn check_default_comparison(
+    db: &dyn SemanticGroup,
+    func_call: &ExprFunctionCall,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let name = func_call.function.name(db);
+    if !COMPARISON_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+        return;
+    }
+    let compares_with_default = func_call.args.iter().any(|arg| match arg {
+        ExprFunctionCallArg::Value(arg) => is_numeric_default(db, *arg, arenas),
+        ExprFunctionCallArg::Reference(_) => false,
+    });
+    if compares_with_default {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: func_call.stable_ptr.into(),
+            message: DEFAULT_COMPARISON.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if an expression is `Default::default()` of a numeric type, or a snapshot of it as
+/// passed to `PartialEq::eq`.
+fn is_numeric_default(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(expr_snapshot) => is_numeric_default(db, expr_snapshot.inner, arenas),
+        Expr::FunctionCall(func_call) => {
+            func_call.function.name(db).ends_with("::default\"")
+                && NUMERIC_TYPES.contains(&func_call.ty.format(db).as_str())
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,83 @@
+use cairo_lang_defs::ids::{GenericParamId, LanguageElementId, ModuleItemId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::imp::ImplLookupContext;
+use cairo_lang_semantic::TypeId;
+use cairo_lang_syntax::node::ast::{Attribute, OptionArguments};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const REDUNDANT_CLONE_DERIVE: &str = "All the fields of this type are `Copy`, and `Copy` types are already \
+                                          `Clone`. Consider deriving `Copy` instead of `Clone`.";
+
+pub fn check_redundant_clone_derive(
+    db: &dyn SemanticGroup,
+    item_id: ModuleItemId,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let syntax_db = db.upcast();
+    let (attributes, generic_params, field_types) = match item_id {
+        ModuleItemId::Struct(struct_id) => {
+            let Ok(members) = db.struct_members(struct_id) else {
+                return;
+            };
+            (
+                struct_id.stable_ptr(db.upcast()).lookup(syntax_db).attributes(syntax_db).elements(syntax_db),
+                db.struct_generic_params(struct_id).unwrap_or_default(),
+                members.values().map(|member| member.ty).collect::<Vec<_>>(),
+            )
+        }
+        ModuleItemId::Enum(enum_id) => {
+            let Ok(variants) = db.enum_variants(enum_id) else {
+                return;
+            };
+            let Ok(field_types) = variants
+                .values()
+                .map(|variant_id| db.variant_semantic(enum_id, *variant_id).map(|variant| variant.ty))
+                .collect::<Result<Vec<_>, _>>()
+            else {
+                return;
+            };
+            (
+                enum_id.stable_ptr(db.upcast()).lookup(syntax_db).attributes(syntax_db).elements(syntax_db),
+                db.enum_generic_params(enum_id).unwrap_or_default(),
+                field_types,
+            )
+        }
+        _ => return,
+    };
+    let Some(clone_derive) = attributes.iter().find(|attribute| derives(syntax_db, attribute, "Clone")) else {
+        return;
+    };
+    // The generic fields aren't `Copy` as far as the derive knows, since it can't rely on bounds.
+    let generic_params: Vec<GenericParamId> = generic_params.iter().map(|param| param.id()).collect();
+    let lookup_context = ImplLookupContext::new(item_id.parent_module(db.upcast()), generic_params);
+    if field_types.iter().all(|ty| is_copy(db, &lookup_context, *ty)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: clone_derive.stable_ptr().untyped(),
+            message: REDUNDANT_CLONE_DERIVE.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if an attribute is a `#[derive(...)]` of the given trait.
+fn derives(db: &dyn SyntaxGroup, attribute: &Attribute, trait_name: &str) -> bool {
+    if attribute.attr(db).as_syntax_node().get_text_without_trivia(db) != "derive" {
+        return false;
+    }
+    let OptionArguments::Arguments(arguments) = attribute.arguments(db) else {
+        return false;
+    };
+    arguments
+        .arguments(db)
+        .elements(db)
+        .iter()
+        .any(|argument| argument.as_syntax_node().get_text_without_trivia(db) == trait_name)
+}
+
+/// Checks if a type is `Copy` where the given context is.
+pub fn is_copy(db: &dyn SemanticGroup, lookup_context: &ImplLookupContext, ty: TypeId) -> bool {
+    db.type_info(lookup_context.clone(), ty).is_ok_and(|type_info| type_info.copyable.is_ok())
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+/// Followed by the other functions having the same body.
+pub const DUPLICATE_FUNCTION_BODY: &str = "This function has the same body as another function of the module.";
+
+/// Reports the functions of a module having the same body as another one, both being reported.
+/// Bodies with less than `min_statements` statements are too common to be worth it.
+pub fn check_duplicate_function_bodies(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    min_statements: usize,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let Ok(free_functions_ids) = db.module_free_functions_ids(module_id) else {
+        return;
+    };
+    let syntax_db = db.upcast();
+    let mut functions_by_body: HashMap<String, Vec<(String, SyntaxStablePtrId)>> = HashMap::new();
+    for free_function_id in free_functions_ids.iter() {
+        let function = free_function_id.stable_ptr(db.upcast()).lookup(syntax_db);
+        let body = function.body(syntax_db);
+        if body.statements(syntax_db).elements(syntax_db).len() < min_statements {
+            continue;
+        }
+        let name = function.declaration(syntax_db).name(syntax_db);
+        functions_by_body
+            .entry(normalized_text(syntax_db, &body.as_syntax_node()))
+            .or_default()
+            .push((name.text(syntax_db).to_string(), name.stable_ptr().untyped()));
+    }
+    for functions in functions_by_body.into_values().filter(|functions| functions.len() > 1) {
+        for (index, (_, stable_ptr)) in functions.iter().enumerate() {
+            let others: Vec<_> = functions
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, (other_name, _))| format!("`{other_name}`"))
+                .collect();
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: *stable_ptr,
+                message: format!(
+                    "{DUPLICATE_FUNCTION_BODY} It is the same as the body of {}. Consider keeping only one of them.",
+                    join_names(&others)
+                ),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Joins names for a message, e.g. `` `a`, `b` and `c` ``.
+fn join_names(names: &[String]) -> String {
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => names.join(""),
+    }
+}
+
+/// Gets the tokens of a node separated by spaces, so that the formatting and the comments don't
+/// matter.
+fn normalized_text(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    node.descendants(db)
+        .filter(|node| node.kind(db).is_terminal())
+        .map(|node| node.get_text_without_trivia(db))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,57 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf};
+use num_bigint::BigInt;
+
+pub const FELT252_FLAG: &str =
+    "This `felt252` is compared to `0` or `1` as if it was a boolean flag. Consider making it a `bool` instead.";
+
+const EQ_SUFFIX: &str = "::eq\"";
+const NE_SUFFIX: &str = "::ne\"";
+
+pub fn check_felt252_flag(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let Condition::BoolExpr(condition) = &if_expr.condition else {
+        return;
+    };
+    let Expr::FunctionCall(func_call) = &arenas.exprs[*condition] else {
+        return;
+    };
+    let name = func_call.function.name(db);
+    if !name.ends_with(EQ_SUFFIX) && !name.ends_with(NE_SUFFIX) {
+        return;
+    }
+    let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] = func_call.args.as_slice() else {
+        return;
+    };
+    let (lhs, rhs) = (&arenas.exprs[unsnapshot(*lhs, arenas)], &arenas.exprs[unsnapshot(*rhs, arenas)]);
+    let compares_flag = |variable, literal| is_felt252_variable(db, variable) && is_flag_literal(literal);
+    if compares_flag(lhs, rhs) || compares_flag(rhs, lhs) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: func_call.stable_ptr.into(),
+            message: FELT252_FLAG.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the expression a snapshot is taken of, as passed to `PartialEq::eq`.
+pub fn unsnapshot(expr_id: ExprId, arenas: &Arenas) -> ExprId {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(expr_snapshot) => unsnapshot(expr_snapshot.inner, arenas),
+        _ => expr_id,
+    }
+}
+
+fn is_felt252_variable(db: &dyn SemanticGroup, expr: &Expr) -> bool {
+    matches!(expr, Expr::Var(expr_var) if expr_var.ty.format(db) == "core::felt252")
+}
+
+fn is_flag_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Literal(literal) if [0, 1].map(BigInt::from).contains(&literal.value))
+}
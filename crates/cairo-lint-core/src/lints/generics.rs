@@ -0,0 +1,34 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{GenericParam, WrappedGenericParamList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const DUPLICATE_BOUND: &str =
+    "This trait bound is already required by an earlier generic parameter. Consider removing it.";
+
+pub fn check_duplicate_bounds(
+    db: &dyn SyntaxGroup,
+    generic_params: &WrappedGenericParamList,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let mut bounds = Vec::new();
+    for param in generic_params.generic_params(db).elements(db) {
+        // Both `+Drop<T>` and `impl TDrop: Drop<T>` require an implementation of the trait.
+        let trait_path = match &param {
+            GenericParam::ImplAnonymous(impl_param) => impl_param.trait_path(db).as_syntax_node(),
+            GenericParam::ImplNamed(impl_param) => impl_param.trait_path(db).as_syntax_node(),
+            _ => continue,
+        };
+        let bound = trait_path.get_text_without_trivia(db).chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        if bounds.contains(&bound) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: param.stable_ptr().untyped(),
+                message: DUPLICATE_BOUND.to_string(),
+                severity: Severity::Warning,
+            });
+        } else {
+            bounds.push(bound);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, BlockOrIf, Condition, Expr, ExprBinary, ExprBlock, ExprIf, OptionElseClause,
+    OptionTerminalSemicolon, PatternIdentifier, Statement, StatementList, StatementReturn,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+use crate::lints::panics::is_panic;
+
+pub const DUPLICATE_IF_BODY: &str =
+    "This `else if` branch has the same body as the previous branch. Consider combining their conditions.";
+pub const IF_LET_ELSE_PANIC: &str = "This `if let` only panics when the pattern doesn't match. Consider using `let \
+                                     ... else` or unwrapping the value instead.";
+pub const IF_ASSIGNMENTS: &str =
+    "Both branches of this `if` only assign the same variable. Consider assigning the `if` expression instead.";
+pub const IF_SAME_RETURN: &str = "Both paths of this `if` return the same value, so the condition doesn't matter. \
+                                  Consider returning the value without the `if`.";
+pub const IF_LET_CHAIN: &str = "Each condition of this `if let` chain destructures the same value. Consider using a \
+                                `match` instead, which also checks that every case is handled.";
+
+pub fn check_duplicate_if_bodies(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    // Only start from the head of the chain so each branch is checked once.
+    if let Some(parent) = if_expr.as_syntax_node().parent()
+        && parent.kind(db) == SyntaxKind::ElseClause
+    {
+        return;
+    }
+    // Only the previous branch can be merged: the conditions of the branches in between could also
+    // hold, and they would no longer be checked first.
+    let mut previous_body = normalized_block_text(db, &if_expr.if_block(db));
+    let mut else_clause = if_expr.else_clause(db);
+    while let OptionElseClause::ElseClause(clause) = else_clause {
+        let BlockOrIf::If(else_if) = clause.else_block_or_if(db) else {
+            break;
+        };
+        let body = normalized_block_text(db, &else_if.if_block(db));
+        if body == previous_body {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: else_if.stable_ptr().untyped(),
+                message: DUPLICATE_IF_BODY.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+        previous_body = body;
+        else_clause = else_if.else_clause(db);
+    }
+}
+
+pub fn check_if_let_chain(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    // Only start from the head of the chain so it's reported once.
+    if let Some(parent) = if_expr.as_syntax_node().parent()
+        && parent.kind(db) == SyntaxKind::ElseClause
+    {
+        return;
+    }
+    let Condition::Let(condition_let) = if_expr.condition(db) else {
+        return;
+    };
+    let scrutinee = condition_let.expr(db).as_syntax_node().get_text_without_trivia(db);
+    let mut has_else_if = false;
+    let mut else_clause = if_expr.else_clause(db);
+    while let OptionElseClause::ElseClause(clause) = else_clause {
+        let BlockOrIf::If(else_if) = clause.else_block_or_if(db) else {
+            break;
+        };
+        let Condition::Let(else_if_let) = else_if.condition(db) else {
+            return;
+        };
+        if else_if_let.expr(db).as_syntax_node().get_text_without_trivia(db) != scrutinee {
+            return;
+        }
+        has_else_if = true;
+        else_clause = else_if.else_clause(db);
+    }
+    if has_else_if {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr().untyped(),
+            message: IF_LET_CHAIN.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+pub fn check_if_let_else_panic(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Condition::Let(condition_let) = if_expr.condition(db) else {
+        return;
+    };
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return;
+    };
+    let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+        return;
+    };
+    // Stay quiet when the `else` branch does anything besides panicking.
+    let [Statement::Expr(else_statement)] = else_block.statements(db).elements(db).as_slice() else {
+        return;
+    };
+    if !is_panic(db, &else_statement.expr(db).as_syntax_node()) {
+        return;
+    }
+    let bindings = condition_let
+        .as_syntax_node()
+        .descendants(db)
+        .filter(|node| node.kind(db) == SyntaxKind::PatternIdentifier)
+        .map(|node| PatternIdentifier::from_syntax_node(db, node).name(db).text(db).to_string())
+        .collect::<Vec<_>>();
+    let uses_binding = if_expr
+        .if_block(db)
+        .as_syntax_node()
+        .descendants(db)
+        .any(|node| node.kind(db) == SyntaxKind::ExprPath && bindings.contains(&node.get_text_without_trivia(db)));
+    if uses_binding {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr().untyped(),
+            message: IF_LET_ELSE_PANIC.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+pub fn check_if_assignments(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    // The `if` must be a statement of its own for its value to be free to assign.
+    if if_expr.as_syntax_node().parent().is_none_or(|parent| parent.kind(db) != SyntaxKind::StatementExpr) {
+        return;
+    }
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return;
+    };
+    let BlockOrIf::Block(else_block) = else_clause.else_block_or_if(db) else {
+        return;
+    };
+    let (Some(if_assignment), Some(else_assignment)) =
+        (single_assignment(db, &if_expr.if_block(db)), single_assignment(db, &else_block))
+    else {
+        return;
+    };
+    let target = |assignment: &ExprBinary| assignment.lhs(db).as_syntax_node().get_text_without_trivia(db);
+    if target(&if_assignment) == target(&else_assignment) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr().untyped(),
+            message: IF_ASSIGNMENTS.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+pub fn check_if_same_return(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    // The bindings of an `if let` may be what's returned.
+    let Condition::Expr(condition) = if_expr.condition(db) else {
+        return;
+    };
+    // Dropping the `if` would also drop the work done by the calls of its condition.
+    let has_calls = condition
+        .as_syntax_node()
+        .descendants(db)
+        .any(|node| matches!(node.kind(db), SyntaxKind::ExprFunctionCall | SyntaxKind::ExprInlineMacro));
+    if has_calls {
+        return;
+    }
+    let Some(if_value) = single_return(db, &if_expr.if_block(db)) else {
+        return;
+    };
+    let other_value = match if_expr.else_clause(db) {
+        OptionElseClause::ElseClause(else_clause) => match else_clause.else_block_or_if(db) {
+            BlockOrIf::Block(else_block) => single_return(db, &else_block),
+            BlockOrIf::If(_) => None,
+        },
+        OptionElseClause::Empty(_) => following_return(db, if_expr),
+    };
+    if other_value.is_some_and(|value| value == if_value) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr().untyped(),
+            message: IF_SAME_RETURN.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the value returned by a block, if it's only a `return`.
+fn single_return(db: &dyn SyntaxGroup, block: &ExprBlock) -> Option<String> {
+    match block.statements(db).elements(db).as_slice() {
+        [Statement::Return(statement)] => Some(returned_value(db, statement)),
+        _ => None,
+    }
+}
+
+/// Gets the value returned by the statement right after an `if`, if it's a `return`.
+fn following_return(db: &dyn SyntaxGroup, if_expr: &ExprIf) -> Option<String> {
+    let statement = if_expr.as_syntax_node().parent().filter(|parent| parent.kind(db) == SyntaxKind::StatementExpr)?;
+    let statements = StatementList::from_syntax_node(db, statement.parent()?).elements(db);
+    let index = statements.iter().position(|other| other.as_syntax_node() == statement)?;
+    match statements.get(index + 1)? {
+        Statement::Return(following) => Some(returned_value(db, following)),
+        _ => None,
+    }
+}
+
+/// Text of the value of a `return` without any whitespace, empty for a `return;`.
+fn returned_value(db: &dyn SyntaxGroup, statement: &StatementReturn) -> String {
+    let value = statement.expr_clause(db).as_syntax_node().get_text_without_trivia(db);
+    value.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Gets the assignment a block is made of, if it's only that assignment.
+pub fn single_assignment(db: &dyn SyntaxGroup, block: &ExprBlock) -> Option<ExprBinary> {
+    let [Statement::Expr(statement)] = block.statements(db).elements(db).as_slice() else {
+        return None;
+    };
+    if let OptionTerminalSemicolon::Empty(_) = statement.semicolon(db) {
+        return None;
+    }
+    match statement.expr(db) {
+        Expr::Binary(binary_expr) if matches!(binary_expr.op(db), BinaryOperator::Eq(_)) => Some(binary_expr),
+        _ => None,
+    }
+}
+
+/// Text of a block without any whitespace so that formatting differences don't matter when
+/// comparing blocks.
+pub fn normalized_block_text(db: &dyn SyntaxGroup, block: &ExprBlock) -> String {
+    block.as_syntax_node().get_text_without_trivia(db).chars().filter(|c| !c.is_whitespace()).collect()
+}
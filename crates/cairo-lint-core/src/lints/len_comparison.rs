@@ -0,0 +1,40 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr, ExprBinary};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const LEN_COMPARISON: &str = "Comparison between the lengths of two collections. If it only guards an index \
+                                  access, consider using a safe accessor such as `get` instead.";
+
+pub fn check_len_comparison(db: &dyn SyntaxGroup, binary_expr: &ExprBinary, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let is_comparison = matches!(
+        binary_expr.op(db),
+        BinaryOperator::EqEq(_)
+            | BinaryOperator::Neq(_)
+            | BinaryOperator::LT(_)
+            | BinaryOperator::GT(_)
+            | BinaryOperator::LE(_)
+            | BinaryOperator::GE(_)
+    );
+    if is_comparison && is_len_call(db, &binary_expr.lhs(db)) && is_len_call(db, &binary_expr.rhs(db)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: binary_expr.stable_ptr().untyped(),
+            message: LEN_COMPARISON.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if an expression is a `collection.len()` method call.
+fn is_len_call(db: &dyn SyntaxGroup, expr: &Expr) -> bool {
+    if let Expr::Binary(method_call) = expr
+        && let BinaryOperator::Dot(_) = method_call.op(db)
+        && let Expr::FunctionCall(func_call) = method_call.rhs(db)
+    {
+        func_call.path(db).as_syntax_node().get_text_without_trivia(db) == "len"
+            && func_call.arguments(db).arguments(db).elements(db).is_empty()
+    } else {
+        false
+    }
+}
@@ -0,0 +1,47 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Expr, OptionTypeClause, StatementLet};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const LITERAL_SUFFIX_MISMATCH: &str = "The suffix of this literal gives it another type than the annotation of \
+                                           the binding. Consider removing the suffix or fixing one of the two types.";
+
+/// Types a numeric literal can be suffixed with. Annotations that aren't in the list may be user
+/// aliases of them, so they're not compared to the suffixes.
+const NUMERIC_TYPES: [&str; 13] =
+    ["felt252", "u8", "u16", "u32", "u64", "u128", "u256", "usize", "i8", "i16", "i32", "i64", "i128"];
+
+/// `usize` is an alias of `u32` in the corelib, so both name the same type.
+fn canonical_numeric_type(name: &str) -> &str {
+    if name == "usize" { "u32" } else { name }
+}
+
+/// The compiler already rejects such a binding, but with the types it expected and found. This
+/// points at the suffix as the cause.
+pub fn check_literal_suffix_mismatch(
+    db: &dyn SyntaxGroup,
+    let_statement: &StatementLet,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let OptionTypeClause::TypeClause(type_clause) = let_statement.type_clause(db) else {
+        return;
+    };
+    let Expr::Literal(literal) = let_statement.rhs(db) else {
+        return;
+    };
+    let Some((_, Some(suffix))) = literal.numeric_value_and_suffix(db) else {
+        return;
+    };
+    let annotation = type_clause.ty(db).as_syntax_node().get_text_without_trivia(db);
+    if NUMERIC_TYPES.contains(&annotation.as_str())
+        && NUMERIC_TYPES.contains(&suffix.as_str())
+        && canonical_numeric_type(&annotation) != canonical_numeric_type(&suffix)
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: literal.stable_ptr().untyped(),
+            message: LITERAL_SUFFIX_MISMATCH.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
@@ -2,11 +2,35 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprLoop, Statement};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, Condition, Expr as AstExpr, ExprWhile, OptionPatternEnumInnerPattern, Pattern,
+    Statement as AstStatement, StatementList,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use num_bigint::BigInt;
+
+use crate::lints::assignments::mutates;
+use crate::lints::panics::is_panic;
 
 pub const LOOP_MATCH_POP_FRONT: &str =
     "you seem to be trying to use `loop` for iterating over a span. Consider using `for in`";
 
+pub const INFINITE_WHILE_LOOP: &str = "The condition of this `while` is true from the start and the body never \
+                                       changes it, so the loop never ends. Consider updating the compared values or \
+                                       adding a `break`.";
+
+pub const ARITHMETIC_SERIES_LOOP: &str = "This loop sums consecutive integers, which has a closed form: the sum from \
+                                          `a` to `b` is `(a + b) * (b - a + 1) / 2`. Consider computing it directly.";
+
+pub const WHILE_LET_NEXT: &str =
+    "This loop takes the elements of an iterator one by one with `next`. Consider using a `for` loop instead.";
+
 const SPAN_MATCH_POP_FRONT: &str = "\"SpanImpl::pop_front\"";
+/// Nodes leaving a loop body, besides the panics.
+const EXIT_KINDS: [SyntaxKind; 3] =
+    [SyntaxKind::StatementBreak, SyntaxKind::StatementReturn, SyntaxKind::ExprErrorPropagate];
 
 pub fn check_loop_match_pop_front(
     db: &dyn SemanticGroup,
@@ -46,3 +70,201 @@ pub fn check_loop_match_pop_front(
         }
     }
 }
+
+pub fn check_infinite_while(db: &dyn SyntaxGroup, while_expr: &ExprWhile, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Condition::Expr(condition) = while_expr.condition(db) else {
+        return;
+    };
+    let AstExpr::Binary(comparison) = condition.expr(db) else {
+        return;
+    };
+    let (lhs, rhs) = (comparison.lhs(db), comparison.rhs(db));
+    let body = while_expr.body(db).as_syntax_node();
+    // Any way out of the body may be how the loop is meant to end.
+    let can_exit = body.descendants(db).any(|node| EXIT_KINDS.contains(&node.kind(db)) || is_panic(db, &node));
+    let is_modified = [&lhs, &rhs].into_iter().any(|operand| match operand {
+        AstExpr::Path(path) => mutates(db, &body, &path.as_syntax_node().get_text_without_trivia(db)),
+        _ => false,
+    });
+    if can_exit || is_modified {
+        return;
+    }
+    let preceding = preceding_statements(db, while_expr);
+    let (Some(lhs), Some(rhs)) = (initial_value(db, &lhs, &preceding), initial_value(db, &rhs, &preceding)) else {
+        return;
+    };
+    let is_initially_true = match comparison.op(db) {
+        BinaryOperator::LT(_) => lhs < rhs,
+        BinaryOperator::LE(_) => lhs <= rhs,
+        BinaryOperator::GT(_) => lhs > rhs,
+        BinaryOperator::GE(_) => lhs >= rhs,
+        BinaryOperator::EqEq(_) => lhs == rhs,
+        BinaryOperator::Neq(_) => lhs != rhs,
+        _ => false,
+    };
+    if is_initially_true {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: while_expr.stable_ptr().untyped(),
+            message: INFINITE_WHILE_LOOP.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Reports the `while` loops made of adding a counter to an accumulator and incrementing the
+/// counter by one, in any order, while the counter is below a bound.
+pub fn check_arithmetic_series_loop(
+    db: &dyn SyntaxGroup,
+    while_expr: &ExprWhile,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let Condition::Expr(condition) = while_expr.condition(db) else {
+        return;
+    };
+    let AstExpr::Binary(comparison) = condition.expr(db) else {
+        return;
+    };
+    let AstExpr::Path(counter) = comparison.lhs(db) else {
+        return;
+    };
+    if !matches!(comparison.op(db), BinaryOperator::LT(_) | BinaryOperator::LE(_)) {
+        return;
+    }
+    let counter = counter.as_syntax_node().get_text_without_trivia(db);
+    let statements = while_expr.body(db).statements(db).elements(db);
+    let [first, second] = statements.as_slice() else {
+        return;
+    };
+    let (Some(first), Some(second)) = (addition(db, first), addition(db, second)) else {
+        return;
+    };
+    let is_step = |(target, value): &(String, AstExpr)| {
+        *target == counter
+            && matches!(value, AstExpr::Literal(literal) if literal.numeric_value(db) == Some(BigInt::from(1)))
+    };
+    let is_accumulation = |(target, value): &(String, AstExpr)| {
+        *target != counter && value.as_syntax_node().get_text_without_trivia(db) == counter
+    };
+    if (is_step(&first) && is_accumulation(&second)) || (is_accumulation(&first) && is_step(&second)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: while_expr.stable_ptr().untyped(),
+            message: ARITHMETIC_SERIES_LOOP.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Reports the `while let Some(x) = it.next()` loops using `x`, unless the body refers to `it`,
+/// which a `for` loop over `it` wouldn't allow.
+pub fn check_while_let_next(db: &dyn SyntaxGroup, while_expr: &ExprWhile, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Condition::Let(condition_let) = while_expr.condition(db) else {
+        return;
+    };
+    let [Pattern::Enum(enum_pattern)] = condition_let.patterns(db).elements(db).as_slice() else {
+        return;
+    };
+    if !matches!(enum_pattern.path(db).as_syntax_node().get_text_without_trivia(db).as_str(), "Some" | "Option::Some") {
+        return;
+    }
+    let OptionPatternEnumInnerPattern::PatternEnumInnerPattern(inner_pattern) = enum_pattern.pattern(db) else {
+        return;
+    };
+    let Pattern::Identifier(element) = inner_pattern.pattern(db) else {
+        return;
+    };
+    let AstExpr::Binary(method_call) = condition_let.expr(db) else {
+        return;
+    };
+    let (AstExpr::Path(iterator), AstExpr::FunctionCall(next_call)) = (method_call.lhs(db), method_call.rhs(db)) else {
+        return;
+    };
+    if !matches!(method_call.op(db), BinaryOperator::Dot(_))
+        || next_call.path(db).as_syntax_node().get_text_without_trivia(db) != "next"
+        || !next_call.arguments(db).arguments(db).elements(db).is_empty()
+    {
+        return;
+    }
+    let (element, iterator) =
+        (element.name(db).text(db).to_string(), iterator.as_syntax_node().get_text_without_trivia(db));
+    let paths = while_expr
+        .body(db)
+        .as_syntax_node()
+        .descendants(db)
+        .filter(|node| node.kind(db) == SyntaxKind::ExprPath)
+        .map(|node| node.get_text_without_trivia(db))
+        .collect::<Vec<_>>();
+    if paths.contains(&element) && !paths.contains(&iterator) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: while_expr.stable_ptr().untyped(),
+            message: WHILE_LET_NEXT.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the variable a statement adds a value to and the value, for `x += v;` and `x = x + v;`.
+fn addition(db: &dyn SyntaxGroup, statement: &AstStatement) -> Option<(String, AstExpr)> {
+    let AstStatement::Expr(statement_expr) = statement else {
+        return None;
+    };
+    let AstExpr::Binary(assignment) = statement_expr.expr(db) else {
+        return None;
+    };
+    let AstExpr::Path(target) = assignment.lhs(db) else {
+        return None;
+    };
+    let target = target.as_syntax_node().get_text_without_trivia(db);
+    let value = match (assignment.op(db), assignment.rhs(db)) {
+        (BinaryOperator::PlusEq(_), value) => value,
+        (BinaryOperator::Eq(_), AstExpr::Binary(sum))
+            if matches!(sum.op(db), BinaryOperator::Plus(_))
+                && sum.lhs(db).as_syntax_node().get_text_without_trivia(db) == target =>
+        {
+            sum.rhs(db)
+        }
+        _ => return None,
+    };
+    Some((target, value))
+}
+
+/// Gets the statements before a `while` in its block, the closest first.
+fn preceding_statements(db: &dyn SyntaxGroup, while_expr: &ExprWhile) -> Vec<AstStatement> {
+    let Some(statement) = while_expr.as_syntax_node().parent() else {
+        return Vec::new();
+    };
+    let Some(statement_list) = statement.parent().filter(|node| node.kind(db) == SyntaxKind::StatementList) else {
+        return Vec::new();
+    };
+    let mut statements = StatementList::from_syntax_node(db, statement_list).elements(db);
+    let Some(index) = statements.iter().position(|other| other.as_syntax_node() == statement) else {
+        return Vec::new();
+    };
+    statements.truncate(index);
+    statements.reverse();
+    statements
+}
+
+/// Gets the value of an operand when the loop starts, if it's an integer literal or a variable
+/// bound to one in the same block and not modified since.
+fn initial_value(db: &dyn SyntaxGroup, operand: &AstExpr, preceding: &[AstStatement]) -> Option<BigInt> {
+    let name = match operand {
+        AstExpr::Literal(literal) => return literal.numeric_value(db),
+        AstExpr::Path(path) => path.as_syntax_node().get_text_without_trivia(db),
+        _ => return None,
+    };
+    for statement in preceding {
+        if let AstStatement::Let(let_statement) = statement
+            && let Pattern::Identifier(identifier) = let_statement.pattern(db)
+            && identifier.name(db).text(db) == name
+        {
+            return match let_statement.rhs(db) {
+                AstExpr::Literal(literal) => literal.numeric_value(db),
+                _ => None,
+            };
+        }
+        if mutates(db, &statement.as_syntax_node(), &name) {
+            return None;
+        }
+    }
+    None
+}
@@ -0,0 +1,96 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_defs::ids::{FreeFunctionId, ModuleId, NamedLanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::ast::TerminalLiteralNumber;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use num_bigint::BigInt;
+
+/// Followed by the number and the other functions it appears in.
+pub const REPEATED_MAGIC_NUMBER: &str = "This number appears in several functions of the crate.";
+
+/// Other functions named in the message, the rest are only counted.
+const NAMED_FUNCTIONS: usize = 3;
+
+/// Reports the numbers of the functions of a module that also appear in enough other functions of
+/// the crate, ignoring the given values. The functions of the whole crate are gone through for
+/// each module, which is only done when the lint is enabled.
+pub fn check_repeated_magic_numbers(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    threshold: usize,
+    ignored: &[i64],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let ignored: Vec<BigInt> = ignored.iter().map(|value| BigInt::from(*value)).collect();
+    let mut functions_by_number: HashMap<BigInt, HashSet<FreeFunctionId>> = HashMap::new();
+    let mut module_numbers = Vec::new();
+    for crate_module_id in db.crate_modules(module_id.owning_crate(db.upcast())).iter() {
+        let Ok(free_functions_ids) = db.module_free_functions_ids(*crate_module_id) else {
+            continue;
+        };
+        for free_function_id in free_functions_ids.iter() {
+            for (number, stable_ptr) in function_numbers(db, *free_function_id) {
+                if ignored.contains(&number) {
+                    continue;
+                }
+                functions_by_number.entry(number.clone()).or_default().insert(*free_function_id);
+                if *crate_module_id == module_id {
+                    module_numbers.push((number, *free_function_id, stable_ptr));
+                }
+            }
+        }
+    }
+    for (number, free_function_id, stable_ptr) in module_numbers {
+        let functions = &functions_by_number[&number];
+        if functions.len() < threshold {
+            continue;
+        }
+        let mut other_functions: Vec<_> = functions
+            .iter()
+            .filter(|other_function_id| **other_function_id != free_function_id)
+            .map(|other_function_id| format!("`{}`", other_function_id.name(db.upcast())))
+            .collect();
+        // The functions are in a set, sorting them keeps the message the same between runs.
+        other_functions.sort();
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr,
+            message: format!(
+                "{REPEATED_MAGIC_NUMBER} `{number}` also appears in {}. Consider giving it a name with a constant.",
+                enumerate_functions(other_functions)
+            ),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Joins the names of functions for a message, e.g. `` `a`, `b`, `c` and 2 other functions ``.
+fn enumerate_functions(mut names: Vec<String>) -> String {
+    if names.len() > NAMED_FUNCTIONS {
+        let others = names.len() - NAMED_FUNCTIONS;
+        names.truncate(NAMED_FUNCTIONS);
+        names.push(format!("{others} other function{}", if others == 1 { "" } else { "s" }));
+    }
+    match names.split_last() {
+        None => "no other function".to_owned(),
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+/// Gets the numeric literals of the body of a function.
+fn function_numbers(db: &dyn SemanticGroup, free_function_id: FreeFunctionId) -> Vec<(BigInt, SyntaxStablePtrId)> {
+    let syntax_db = db.upcast();
+    let body = free_function_id.stable_ptr(db.upcast()).lookup(syntax_db).body(syntax_db).as_syntax_node();
+    body.descendants(syntax_db)
+        .filter(|node| node.kind(syntax_db) == SyntaxKind::TerminalLiteralNumber)
+        .filter_map(|node| {
+            let number = TerminalLiteralNumber::from_syntax_node(syntax_db, node.clone()).numeric_value(syntax_db)?;
+            Some((number, node.stable_ptr()))
+        })
+        .collect()
+}
@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::corelib::core_bool_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ConcreteTypeId, ExprMatch, Pattern, PatternId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, Expr as AstExpr, ExprBinary as AstExprBinary, ExprMatch as AstExprMatch, ExprUnary as AstExprUnary,
+    MatchArm, OptionPatternEnumInnerPattern, OptionTerminalSemicolon, Pattern as AstPattern, Statement as AstStatement,
+    UnaryOperator,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::LookupIntern;
+
+pub const UNREACHABLE_WILDCARD: &str =
+    "All the variants of the enum are already matched, this wildcard arm is unreachable. Consider removing it.";
+pub const NESTED_MATCH: &str = "This `match` only matches again on the value bound by one of its arms. Consider \
+                                combining the patterns, e.g. `Option::Some(Option::Some(x))`.";
+pub const BOOL_MATCH_WILDCARD: &str =
+    "Both `true` and `false` are already matched, this wildcard arm is unreachable. Consider removing it.";
+pub const INVERTED_BOOL_MATCH: &str = "This `match` maps `true` to `false` and `false` to `true`. Check that the arms \
+                                       aren't swapped, or negate the value with `!` instead.";
+pub const UNUSED_MATCH_SCRUTINEE: &str = "Every arm of this `match` is a wildcard, so the matched expression is \
+                                          evaluated for nothing. Consider using the body of the first arm directly.";
+
+pub fn check_unreachable_wildcard(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let Some((last_arm, other_arms)) = match_expr.arms.split_last() else {
+        return;
+    };
+    let [last_pattern] = last_arm.patterns.as_slice() else {
+        return;
+    };
+    let Pattern::Otherwise(wildcard) = &arenas.patterns[*last_pattern] else {
+        return;
+    };
+    let matched_ty = arenas.exprs[match_expr.matched_expr].ty();
+    // Matches on `bool` use literal patterns, they're handled by `check_bool_match_wildcard`.
+    if matched_ty == core_bool_ty(db) {
+        return;
+    }
+    let Some(variants_count) = enum_variants_count(db, matched_ty) else {
+        return;
+    };
+    let mut matched_variants = HashSet::new();
+    for pattern in other_arms.iter().flat_map(|arm| &arm.patterns) {
+        // A variant is only fully matched if its inner value can't make the pattern fail.
+        if let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[*pattern]
+            && enum_pattern.inner_pattern.is_none_or(|inner_pattern| is_irrefutable(inner_pattern, arenas))
+        {
+            matched_variants.insert(enum_pattern.variant.idx);
+        }
+    }
+    if matched_variants.len() == variants_count {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: wildcard.stable_ptr.untyped(),
+            message: UNREACHABLE_WILDCARD.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the number of variants of an enum type, snapshots of enums included.
+fn enum_variants_count(db: &dyn SemanticGroup, ty: TypeId) -> Option<usize> {
+    match ty.lookup_intern(db) {
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            db.enum_variants(concrete_enum_id.enum_id(db)).ok().map(|variants| variants.len())
+        }
+        TypeLongId::Snapshot(inner_ty) => enum_variants_count(db, inner_ty),
+        _ => None,
+    }
+}
+
+fn is_irrefutable(pattern: PatternId, arenas: &Arenas) -> bool {
+    matches!(arenas.patterns[pattern], Pattern::Variable(_) | Pattern::Otherwise(_))
+}
+
+pub fn check_nested_match(db: &dyn SyntaxGroup, match_expr: &AstExprMatch, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Some((binding_arm, outer_wildcard_arm)) = split_wildcard_arm(db, match_expr) else {
+        return;
+    };
+    let [AstPattern::Enum(enum_pattern)] = binding_arm.patterns(db).elements(db).as_slice() else {
+        return;
+    };
+    let OptionPatternEnumInnerPattern::PatternEnumInnerPattern(inner_pattern) = enum_pattern.pattern(db) else {
+        return;
+    };
+    let AstPattern::Identifier(binding) = inner_pattern.pattern(db) else {
+        return;
+    };
+    let Some(AstExpr::Match(inner_match)) = single_expression(db, binding_arm.expression(db)) else {
+        return;
+    };
+    let binding_name = binding.name(db).text(db).to_string();
+    if inner_match.expr(db).as_syntax_node().get_text_without_trivia(db) != binding_name {
+        return;
+    }
+    let Some((inner_binding_arm, inner_wildcard_arm)) = split_wildcard_arm(db, &inner_match) else {
+        return;
+    };
+    // The bound value disappears once the patterns are combined, so it must only be matched on.
+    let uses_binding = [&inner_binding_arm, &inner_wildcard_arm].iter().any(|arm| {
+        arm.expression(db)
+            .as_syntax_node()
+            .descendants(db)
+            .any(|node| node.kind(db) == SyntaxKind::ExprPath && node.get_text_without_trivia(db) == binding_name)
+    });
+    // The values that don't match either pattern must end up in the same place.
+    let same_fallback = normalized_text(db, &outer_wildcard_arm.expression(db).as_syntax_node())
+        == normalized_text(db, &inner_wildcard_arm.expression(db).as_syntax_node());
+    if !uses_binding && same_fallback {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: match_expr.stable_ptr().untyped(),
+            message: NESTED_MATCH.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Splits the arms of a match with two arms, one of them being a wildcard, into the arm with an
+/// enum pattern and the wildcard arm.
+fn split_wildcard_arm(db: &dyn SyntaxGroup, match_expr: &AstExprMatch) -> Option<(MatchArm, MatchArm)> {
+    let [first_arm, second_arm] = <[MatchArm; 2]>::try_from(match_expr.arms(db).elements(db)).ok()?;
+    let is_wildcard = |arm: &MatchArm| matches!(arm.patterns(db).elements(db).as_slice(), [AstPattern::Underscore(_)]);
+    let is_enum = |arm: &MatchArm| matches!(arm.patterns(db).elements(db).as_slice(), [AstPattern::Enum(_)]);
+    if is_enum(&first_arm) && is_wildcard(&second_arm) {
+        Some((first_arm, second_arm))
+    } else if is_wildcard(&first_arm) && is_enum(&second_arm) {
+        Some((second_arm, first_arm))
+    } else {
+        None
+    }
+}
+
+/// Gets the expression a block is made of, if it's only that expression.
+fn single_expression(db: &dyn SyntaxGroup, expr: AstExpr) -> Option<AstExpr> {
+    let AstExpr::Block(block) = expr else {
+        return Some(expr);
+    };
+    match block.statements(db).elements(db).as_slice() {
+        [AstStatement::Expr(statement)] if matches!(statement.semicolon(db), OptionTerminalSemicolon::Empty(_)) => {
+            Some(statement.expr(db))
+        }
+        _ => None,
+    }
+}
+
+fn normalized_text(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    node.get_text_without_trivia(db).chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+pub fn check_bool_match_wildcard(
+    db: &dyn SyntaxGroup,
+    match_expr: &AstExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let (mut matches_true, mut matches_false) = (false, false);
+    for arm in match_expr.arms(db).elements(db) {
+        let patterns = arm.patterns(db).elements(db);
+        if let [AstPattern::Underscore(wildcard)] = patterns.as_slice()
+            && matches_true
+            && matches_false
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: wildcard.stable_ptr().untyped(),
+                message: BOOL_MATCH_WILDCARD.to_owned(),
+                severity: Severity::Warning,
+            });
+            return;
+        }
+        matches_true |= patterns.iter().any(|pattern| matches!(pattern, AstPattern::True(_)));
+        matches_false |= patterns.iter().any(|pattern| matches!(pattern, AstPattern::False(_)));
+    }
+}
+
+pub fn check_inverted_bool_match(
+    db: &dyn SyntaxGroup,
+    match_expr: &AstExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let Ok(arms) = <[MatchArm; 2]>::try_from(match_expr.arms(db).elements(db)) else {
+        return;
+    };
+    let is_inverted = arms.iter().all(|arm| {
+        match (arm.patterns(db).elements(db).as_slice(), single_expression(db, arm.expression(db))) {
+            ([AstPattern::True(_)], Some(AstExpr::False(_))) | ([AstPattern::False(_)], Some(AstExpr::True(_))) => true,
+            _ => false,
+        }
+    });
+    if is_inverted {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: match_expr.stable_ptr().untyped(),
+            message: INVERTED_BOOL_MATCH.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+pub fn check_unused_match_scrutinee(
+    db: &dyn SyntaxGroup,
+    match_expr: &AstExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let arms = match_expr.arms(db).elements(db);
+    // A match without arms, on an empty enum, doesn't discard anything.
+    if arms.is_empty() {
+        return;
+    }
+    let only_wildcards = arms
+        .iter()
+        .flat_map(|arm| arm.patterns(db).elements(db))
+        .all(|pattern| matches!(pattern, AstPattern::Underscore(_)));
+    let scrutinee = match_expr.expr(db);
+    let is_trivial = matches!(
+        scrutinee,
+        AstExpr::Path(_)
+            | AstExpr::Literal(_)
+            | AstExpr::ShortString(_)
+            | AstExpr::String(_)
+            | AstExpr::True(_)
+            | AstExpr::False(_)
+    );
+    // Dropping a call could drop a side effect the code relies on, and so could dropping arithmetic
+    // that panics when it overflows or divides by zero.
+    let has_side_effects = scrutinee.as_syntax_node().descendants(db).any(|node| match node.kind(db) {
+        SyntaxKind::ExprFunctionCall | SyntaxKind::ExprInlineMacro | SyntaxKind::ExprIndexed => true,
+        SyntaxKind::ExprBinary => matches!(
+            AstExprBinary::from_syntax_node(db, node).op(db),
+            BinaryOperator::Plus(_)
+                | BinaryOperator::Minus(_)
+                | BinaryOperator::Mul(_)
+                | BinaryOperator::Div(_)
+                | BinaryOperator::Mod(_)
+        ),
+        SyntaxKind::ExprUnary => matches!(AstExprUnary::from_syntax_node(db, node).op(db), UnaryOperator::Minus(_)),
+        _ => false,
+    });
+    if only_wildcards && !is_trivial && !has_side_effects {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: match_expr.stable_ptr().untyped(),
+            message: UNUSED_MATCH_SCRUTINEE.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
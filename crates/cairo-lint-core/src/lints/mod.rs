@@ -1,6 +1,32 @@
+pub mod arithmetic;
+pub mod asserts;
+pub mod assignments;
 pub mod bool_comparison;
+pub mod bounds;
 pub mod breaks;
+pub mod collections;
+pub mod comments;
+pub mod conversions;
+pub mod defaults;
+pub mod derives;
 pub mod double_comparison;
 pub mod double_parens;
+pub mod duplicates;
+pub mod flags;
+pub mod generics;
+pub mod ifs;
+pub mod len_comparison;
+pub mod literals;
 pub mod loops;
+pub mod magic_numbers;
+pub mod match_arms;
+pub mod naming;
+pub mod panics;
+pub mod parameters;
+pub mod recursion;
+pub mod returns;
+pub mod self_comparison;
 pub mod single_match;
+pub mod snapshots;
+pub mod tautologies;
+pub mod yoda_comparison;
@@ -0,0 +1,21 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::ItemEnum;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const VARIANT_NAMED_LIKE_ENUM: &str =
+    "This variant has the same name as its enum, which is confusing where it's used. Consider renaming it.";
+
+pub fn check_variant_named_like_enum(db: &dyn SyntaxGroup, item: &ItemEnum, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let enum_name = item.name(db).text(db);
+    for variant in item.variants(db).elements(db) {
+        if variant.name(db).text(db) == enum_name {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: variant.stable_ptr().untyped(),
+                message: VARIANT_NAMED_LIKE_ENUM.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
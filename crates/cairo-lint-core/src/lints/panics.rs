@@ -0,0 +1,43 @@
+use cairo_lang_defs::ids::FreeFunctionId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::ast::{ExprFunctionCall, ExprInlineMacro};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+pub const PANIC_IN_RESULT: &str =
+    "Panicking in a function that returns a `Result`. Consider returning an `Err` instead.";
+
+const PANIC_FUNCTIONS: [&str; 2] = ["panic", "panic_with_felt252"];
+const RESULT_TYPE_PREFIX: &str = "core::result::Result::<";
+
+pub fn check_panic_in_result(db: &dyn SyntaxGroup, node: SyntaxNode, diagnostics: &mut Vec<PluginDiagnostic>) {
+    if is_panic(db, &node) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: node.stable_ptr(),
+            message: PANIC_IN_RESULT.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if a node is a `panic!` macro call or a call to one of the panic functions.
+pub fn is_panic(db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    let path = match node.kind(db) {
+        SyntaxKind::ExprInlineMacro => ExprInlineMacro::from_syntax_node(db, node.clone()).path(db).as_syntax_node(),
+        SyntaxKind::ExprFunctionCall => ExprFunctionCall::from_syntax_node(db, node.clone()).path(db).as_syntax_node(),
+        _ => return false,
+    };
+    let path_text = path.get_text_without_trivia(db);
+    // Calls can be qualified (`core::panic_with_felt252`), only the function name matters.
+    let name = path_text.rsplit("::").next().unwrap_or_default();
+    PANIC_FUNCTIONS.contains(&name)
+}
+
+/// Checks if a free function returns a `Result`.
+pub fn returns_result(db: &dyn SemanticGroup, free_function_id: FreeFunctionId) -> bool {
+    db.free_function_signature(free_function_id)
+        .is_ok_and(|signature| signature.return_type.format(db).starts_with(RESULT_TYPE_PREFIX))
+}
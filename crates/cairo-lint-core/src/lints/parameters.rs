@@ -0,0 +1,113 @@
+use cairo_lang_defs::ids::{FreeFunctionId, LanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::items::imp::ImplLookupContext;
+use cairo_lang_semantic::{
+    Arenas, ConcreteTypeId, Expr, ExprFunctionCall, ExprFunctionCallArg, Mutability, TypeId, TypeLongId,
+};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::LookupIntern;
+
+use crate::lints::assignments::mutates;
+use crate::lints::derives::is_copy;
+
+pub const LARGE_STRUCT_BY_VALUE: &str = "This parameter is a large struct taken by value, which copies all of its \
+                                         fields. Consider taking a snapshot instead.";
+pub const SNAPSHOT_OF_COPY_VALUE: &str =
+    "This argument is a snapshot of a `Copy` value. Consider having the function take it by value instead.";
+pub const REF_OF_COPY_VALUE: &str = "This argument is a `Copy` value passed by `ref` to a function that never \
+                                     modifies it. Consider having the function take it by value instead.";
+
+pub fn check_large_struct_by_value(
+    db: &dyn SemanticGroup,
+    free_function_id: FreeFunctionId,
+    threshold: usize,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let Ok(signature) = db.free_function_signature(free_function_id) else {
+        return;
+    };
+    for param in &signature.params {
+        // `ref` parameters aren't copied.
+        if param.mutability == Mutability::Reference {
+            continue;
+        }
+        let is_struct = matches!(param.ty.lookup_intern(db), TypeLongId::Concrete(ConcreteTypeId::Struct(_)));
+        if is_struct && fields_count(db, param.ty) > threshold {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: param.stable_ptr.untyped(),
+                message: LARGE_STRUCT_BY_VALUE.to_owned(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Counts the fields that are copied along with a value, the fields of the nested structs and
+/// tuples included. Any other type counts as a single field.
+fn fields_count(db: &dyn SemanticGroup, ty: TypeId) -> usize {
+    match ty.lookup_intern(db) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => db
+            .concrete_struct_members(concrete_struct_id)
+            .map(|members| members.values().map(|member| fields_count(db, member.ty)).sum())
+            .unwrap_or(1),
+        TypeLongId::Tuple(types) => types.into_iter().map(|ty| fields_count(db, ty)).sum(),
+        _ => 1,
+    }
+}
+
+pub fn check_copy_value_indirection(
+    db: &dyn SemanticGroup,
+    free_function_id: FreeFunctionId,
+    func_call: &ExprFunctionCall,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let GenericFunctionId::Free(callee_id) = func_call.function.get_concrete(db).generic_function else {
+        return;
+    };
+    // The signatures of the functions of other crates can't be changed.
+    let crate_of = |function_id: FreeFunctionId| function_id.parent_module(db.upcast()).owning_crate(db.upcast());
+    if crate_of(callee_id) != crate_of(free_function_id) {
+        return;
+    }
+    let Ok(signature) = db.free_function_signature(callee_id) else {
+        return;
+    };
+    let generic_params = db.free_function_generic_params(free_function_id).unwrap_or_default();
+    let lookup_context = ImplLookupContext::new(
+        free_function_id.parent_module(db.upcast()),
+        generic_params.iter().map(|param| param.id()).collect(),
+    );
+    let syntax_db = db.upcast();
+    let callee_body = callee_id.stable_ptr(db.upcast()).lookup(syntax_db).body(syntax_db).as_syntax_node();
+    for (arg, param) in func_call.args.iter().zip(&signature.params) {
+        match arg {
+            // The parameter must be a snapshot itself, a generic one may only be so for some calls.
+            ExprFunctionCallArg::Value(arg) => {
+                if let Expr::Snapshot(snapshot) = &arenas.exprs[*arg]
+                    && matches!(param.ty.lookup_intern(db), TypeLongId::Snapshot(_))
+                    && is_copy(db, &lookup_context, arenas.exprs[snapshot.inner].ty())
+                {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: snapshot.stable_ptr.untyped(),
+                        message: SNAPSHOT_OF_COPY_VALUE.to_owned(),
+                        severity: Severity::Warning,
+                    });
+                }
+            }
+            // The caller sees the changes made through a reference, they must not be any.
+            ExprFunctionCallArg::Reference(var) => {
+                if is_copy(db, &lookup_context, var.ty()) && !mutates(syntax_db, &callee_body, &param.name) {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: var.stable_ptr().untyped(),
+                        message: REF_OF_COPY_VALUE.to_owned(),
+                        severity: Severity::Warning,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use cairo_lang_defs::ids::FreeFunctionId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::corelib::never_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, ExprId, Statement};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+pub const INFINITE_RECURSION: &str =
+    "Every path through this function calls itself, so it will recurse infinitely. Consider adding a base case.";
+
+pub fn check_infinite_recursion(
+    db: &dyn SemanticGroup,
+    free_function_id: FreeFunctionId,
+    body_expr: ExprId,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    // Early exits make the paths too hard to follow, stay quiet rather than guessing. Calls that
+    // never return are panics, e.g. the base case of `if n == 0 { panic!(..) }`.
+    let never = never_ty(db);
+    let has_early_exit = arenas.exprs.iter().any(|(_, expr)| match expr {
+        Expr::Loop(_) | Expr::While(_) | Expr::For(_) | Expr::PropagateError(_) => true,
+        Expr::FunctionCall(func_call) => func_call.ty == never,
+        _ => false,
+    }) || arenas.statements.iter().any(|(_, statement)| matches!(statement, Statement::Return(_)));
+    if !has_early_exit && always_recurses(db, free_function_id, body_expr, arenas) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: free_function_id.stable_ptr(db.upcast()).untyped(),
+            message: INFINITE_RECURSION.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if every path through an expression calls the given function. Anything that isn't a
+/// call, a block, an `if` or a `match` is assumed to have a path that doesn't.
+fn always_recurses(db: &dyn SemanticGroup, function_id: FreeFunctionId, expr_id: ExprId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::FunctionCall(func_call) => {
+            let is_self_call = matches!(
+                func_call.function.get_concrete(db).generic_function,
+                GenericFunctionId::Free(id) if id == function_id
+            );
+            is_self_call
+                || func_call.args.iter().any(|arg| match arg {
+                    ExprFunctionCallArg::Value(arg) => always_recurses(db, function_id, *arg, arenas),
+                    ExprFunctionCallArg::Reference(_) => false,
+                })
+        }
+        Expr::Block(expr_block) => {
+            expr_block.statements.iter().any(|statement| match &arenas.statements[*statement] {
+                Statement::Expr(stmt_expr) => always_recurses(db, function_id, stmt_expr.expr, arenas),
+                Statement::Let(stmt_let) => always_recurses(db, function_id, stmt_let.expr, arenas),
+                _ => false,
+            }) || expr_block.tail.is_some_and(|tail| always_recurses(db, function_id, tail, arenas))
+        }
+        Expr::If(expr_if) => expr_if.else_block.is_some_and(|else_block| {
+            always_recurses(db, function_id, expr_if.if_block, arenas)
+                && always_recurses(db, function_id, else_block, arenas)
+        }),
+        Expr::Match(expr_match) => {
+            always_recurses(db, function_id, expr_match.matched_expr, arenas)
+                || (!expr_match.arms.is_empty()
+                    && expr_match.arms.iter().all(|arm| always_recurses(db, function_id, arm.expression, arenas)))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,71 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Expr, FunctionWithBody, OptionExprClause, OptionReturnTypeClause, StatementReturn};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const NEEDLESS_RETURN_BLOCK: &str = "This `return` of a block is the last statement of the function. Consider \
+                                         making the block the tail expression instead.";
+pub const NEEDLESS_UNIT_RETURN: &str =
+    "This `return` is the last statement of a function returning `()`, which it returns anyway. Consider removing it.";
+
+pub fn check_needless_return_block(
+    db: &dyn SyntaxGroup,
+    return_statement: &StatementReturn,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let OptionExprClause::ExprClause(expr_clause) = return_statement.expr_clause(db) else {
+        return;
+    };
+    if !matches!(expr_clause.expr(db), Expr::Block(_)) {
+        return;
+    }
+    // Only the last statement of the body of a function can become its tail expression.
+    if ending_function(db, return_statement).is_some() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: return_statement.stable_ptr().untyped(),
+            message: NEEDLESS_RETURN_BLOCK.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Reports the bare `return;` ending a function without a return type or returning `()`. A
+/// `return ();` is left alone, it states the returned value.
+pub fn check_needless_unit_return(
+    db: &dyn SyntaxGroup,
+    return_statement: &StatementReturn,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if !matches!(return_statement.expr_clause(db), OptionExprClause::Empty(_)) {
+        return;
+    }
+    let Some(function) = ending_function(db, return_statement) else {
+        return;
+    };
+    let returns_unit = match function.declaration(db).signature(db).ret_ty(db) {
+        OptionReturnTypeClause::Empty(_) => true,
+        OptionReturnTypeClause::ReturnTypeClause(return_type) => {
+            return_type.ty(db).as_syntax_node().get_text_without_trivia(db) == "()"
+        }
+    };
+    if returns_unit {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: return_statement.stable_ptr().untyped(),
+            message: NEEDLESS_UNIT_RETURN.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the function of which a `return` is the last statement of the body.
+fn ending_function(db: &dyn SyntaxGroup, return_statement: &StatementReturn) -> Option<FunctionWithBody> {
+    let statement = return_statement.as_syntax_node();
+    let statement_list = statement.parent()?;
+    if statement_list.children(db).last().is_none_or(|last_statement| last_statement != statement) {
+        return None;
+    }
+    let function = statement_list.parent()?.parent()?;
+    (function.kind(db) == SyntaxKind::FunctionWithBody).then(|| FunctionWithBody::from_syntax_node(db, function))
+}
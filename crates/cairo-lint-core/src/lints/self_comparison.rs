@@ -0,0 +1,46 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId};
+
+use crate::lints::defaults::COMPARISON_SUFFIXES;
+use crate::lints::flags::unsnapshot;
+
+pub const SELF_COMPARISON: &str =
+    "This value is compared to itself, so the result is always the same. Consider comparing it to another value.";
+
+pub fn check_self_comparison(
+    db: &dyn SemanticGroup,
+    func_call: &ExprFunctionCall,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let name = func_call.function.name(db);
+    if !COMPARISON_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+        return;
+    }
+    let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] = func_call.args.as_slice() else {
+        return;
+    };
+    if is_same_binding(*lhs, *rhs, arenas) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: func_call.stable_ptr.into(),
+            message: SELF_COMPARISON.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if two expressions are paths to the same member of the same binding, like `x` and `x` or
+/// `p.x` and `p.x`. A shadowing `let` creates another binding, and nothing can be assigned between
+/// the operands of a comparison, so both always have the same value then. Anything else, like a
+/// call or a block, may give another value each time it's evaluated.
+fn is_same_binding(first: ExprId, second: ExprId, arenas: &Arenas) -> bool {
+    match (&arenas.exprs[unsnapshot(first, arenas)], &arenas.exprs[unsnapshot(second, arenas)]) {
+        (Expr::Var(first), Expr::Var(second)) => first.var == second.var,
+        (Expr::MemberAccess(first), Expr::MemberAccess(second)) => {
+            first.member == second.member && is_same_binding(first.expr, second.expr, arenas)
+        }
+        _ => false,
+    }
+}
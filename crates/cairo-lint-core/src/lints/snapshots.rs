@@ -0,0 +1,85 @@
+use std::iter;
+
+use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, ExprSnapshot, TypeLongId, VarId};
+use cairo_lang_syntax::node::ast::{Expr as AstExpr, UnaryOperator};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::LookupIntern;
+
+pub const REDUNDANT_SNAPSHOT: &str =
+    "This value is already a snapshot, taking another one is redundant. Consider removing the extra `@`.";
+pub const CLONE_IN_SNAPSHOT_MATCH: &str = "This clones a snapshot bound by a `match` arm only to take a snapshot of \
+                                           the clone. Consider using the binding directly.";
+
+const CLONE_SUFFIX: &str = "::clone\"";
+
+pub fn check_redundant_snapshot(
+    db: &dyn SemanticGroup,
+    snapshot: &ExprSnapshot,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let inner = &arenas.exprs[snapshot.inner];
+    if !matches!(inner.ty().lookup_intern(db), TypeLongId::Snapshot(_)) {
+        return;
+    }
+    // The snapshots taken by the compiler, like the ones of the operands of `==`, point to the
+    // operand itself instead of an `@` of it.
+    let syntax_db = db.upcast();
+    let AstExpr::Unary(unary) = snapshot.stable_ptr.lookup(syntax_db) else {
+        return;
+    };
+    if matches!(unary.op(syntax_db), UnaryOperator::At(_)) && unary.expr(syntax_db).stable_ptr() == inner.stable_ptr() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: snapshot.stable_ptr.untyped(),
+            message: REDUNDANT_SNAPSHOT.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Reports the clones of a snapshot bound by the pattern of a `match` arm of which a snapshot is
+/// taken, explicitly or by the compiler to pass it as an operand or a `self` argument. This gives a
+/// snapshot of the same value as the binding, without the cost of the clone.
+pub fn check_clone_in_snapshot_match(
+    db: &dyn SemanticGroup,
+    snapshot: &ExprSnapshot,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    let Expr::FunctionCall(clone_call) = &arenas.exprs[snapshot.inner] else {
+        return;
+    };
+    if !clone_call.function.name(db).ends_with(CLONE_SUFFIX) {
+        return;
+    }
+    let [ExprFunctionCallArg::Value(cloned)] = clone_call.args.as_slice() else {
+        return;
+    };
+    let Expr::Var(cloned) = &arenas.exprs[*cloned] else {
+        return;
+    };
+    let VarId::Local(local_var_id) = cloned.var else {
+        return;
+    };
+    if !matches!(cloned.ty.lookup_intern(db), TypeLongId::Snapshot(_)) {
+        return;
+    }
+    // The binding is in the patterns of an arm if the patterns are the child of the arm it's in.
+    let syntax_db = db.upcast();
+    let binding = local_var_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node();
+    let is_arm_binding = iter::successors(Some(binding), |node| node.parent())
+        .find(|node| node.parent().is_some_and(|parent| parent.kind(syntax_db) == SyntaxKind::MatchArm))
+        .is_some_and(|node| node.kind(syntax_db) == SyntaxKind::PatternListOr);
+    if is_arm_binding {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: clone_call.stable_ptr.untyped(),
+            message: CLONE_IN_SNAPSHOT_MATCH.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
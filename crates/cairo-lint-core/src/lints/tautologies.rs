@@ -0,0 +1,49 @@
+use cairo_lang_defs::ids::NamedLanguageElementId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprId, ExprLogicalOperator, LogicalOperator};
+
+use crate::lints::bounds::comparison_result;
+
+pub const ALWAYS_TRUE_OR_SKIPPED_OPERAND: &str = "The first operand of this `||` is always true, so the condition is \
+                                                  too and the second operand is never evaluated. Consider removing \
+                                                  the condition.";
+pub const ALWAYS_TRUE_OR_IGNORED_OPERAND: &str = "The second operand of this `||` is always true, so the condition is \
+                                                  too and the first operand doesn't change the result. Consider \
+                                                  removing the condition.";
+
+/// Reports the `||` of which an operand is always true, being `true` or an ordering comparison
+/// with a bound the compared value can't cross.
+pub fn check_always_true_or(
+    db: &dyn SemanticGroup,
+    logical_operator: &ExprLogicalOperator,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    if !matches!(logical_operator.op, LogicalOperator::OrOr) {
+        return;
+    }
+    let message = if is_always_true(db, logical_operator.lhs, arenas) {
+        ALWAYS_TRUE_OR_SKIPPED_OPERAND
+    } else if is_always_true(db, logical_operator.rhs, arenas) {
+        ALWAYS_TRUE_OR_IGNORED_OPERAND
+    } else {
+        return;
+    };
+    diagnostics.push(PluginDiagnostic {
+        stable_ptr: logical_operator.stable_ptr.into(),
+        message: message.to_owned(),
+        severity: Severity::Warning,
+    });
+}
+
+fn is_always_true(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::EnumVariantCtor(ctor) => {
+            ctor.ty.format(db) == "core::bool" && ctor.variant.id.name(db.upcast()) == "True"
+        }
+        Expr::FunctionCall(func_call) => comparison_result(db, func_call, arenas) == Some(true),
+        _ => false,
+    }
+}
@@ -0,0 +1,37 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr, ExprBinary};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+pub const YODA_COMPARISON: &str = "The constant is on the left of this comparison. Consider swapping the operands.";
+
+pub fn check_yoda_comparison(db: &dyn SyntaxGroup, binary_expr: &ExprBinary, diagnostics: &mut Vec<PluginDiagnostic>) {
+    if swapped_operator(&binary_expr.op(db)).is_some()
+        && is_constant(&binary_expr.lhs(db))
+        && !is_constant(&binary_expr.rhs(db))
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: binary_expr.stable_ptr().untyped(),
+            message: YODA_COMPARISON.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the operator that keeps the result of a comparison the same when its operands are swapped.
+pub fn swapped_operator(op: &BinaryOperator) -> Option<&'static str> {
+    match op {
+        BinaryOperator::EqEq(_) => Some("=="),
+        BinaryOperator::Neq(_) => Some("!="),
+        BinaryOperator::LT(_) => Some(">"),
+        BinaryOperator::GT(_) => Some("<"),
+        BinaryOperator::LE(_) => Some(">="),
+        BinaryOperator::GE(_) => Some("<="),
+        _ => None,
+    }
+}
+
+fn is_constant(expr: &Expr) -> bool {
+    matches!(expr, Expr::Literal(_) | Expr::ShortString(_) | Expr::String(_))
+}
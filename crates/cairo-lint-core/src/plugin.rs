@@ -1,23 +1,199 @@
-use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::sync::Arc;
+use std::time::Instant;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
-use cairo_lang_semantic::Expr;
-use cairo_lang_syntax::node::ast::{Expr as AstExpr, ExprBinary};
+use cairo_lang_semantic::{Expr, Statement};
+use cairo_lang_syntax::node::ast::{
+    Expr as AstExpr, ExprBinary, ExprBlock, ExprIf, ExprInlineMacro, ExprMatch, ExprWhile, FunctionWithBody,
+    ItemConstant, ItemEnum, ItemStruct, StatementLet, StatementReturn, Visibility, WrappedGenericParamList,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 
-use crate::lints::{bool_comparison, breaks, double_comparison, double_parens, loops, single_match};
+use crate::config::CairoLintConfig;
+use crate::lints::{
+    arithmetic, asserts, assignments, bool_comparison, bounds, breaks, collections, comments, conversions, defaults,
+    derives, double_comparison, double_parens, duplicates, flags, generics, ifs, len_comparison, literals, loops,
+    magic_numbers, match_arms, naming, panics, parameters, recursion, returns, self_comparison, single_match,
+    snapshots, tautologies, yoda_comparison,
+};
+use crate::profiling::LintTimings;
+use crate::suppressions;
 
 pub fn cairo_lint_plugin_suite() -> PluginSuite {
     let mut suite = PluginSuite::default();
     suite.add_analyzer_plugin::<CairoLint>();
     suite
 }
-#[derive(Debug, Default)]
-pub struct CairoLint;
 
-#[derive(Debug, PartialEq)]
+/// Same as [`cairo_lint_plugin_suite`] but the lints are run with the given configuration.
+pub fn cairo_lint_plugin_suite_with_config(config: CairoLintConfig) -> PluginSuite {
+    let mut suite = PluginSuite::default();
+    suite.add_analyzer_plugin_ex(Arc::new(CairoLint::new(config)));
+    suite
+}
+
+/// Same as [`cairo_lint_plugin_suite_with_config`] but the time spent in each check is recorded
+/// in the given timings.
+pub fn cairo_lint_plugin_suite_with_timings(config: CairoLintConfig, timings: Arc<LintTimings>) -> PluginSuite {
+    let mut suite = PluginSuite::default();
+    suite.add_analyzer_plugin_ex(Arc::new(CairoLint::with_timings(config, timings)));
+    suite
+}
+
+/// Runs a check of a lint, recording how long it takes when profiling.
+macro_rules! timed {
+    ($lint:expr, $($check:ident)::+ ( $($arg:expr),* $(,)? )) => {
+        $lint.run_check(stringify!($($check)::+), || $($check)::+($($arg),*))
+    };
+}
+
+/// Same as [`CairoLint::lint_node`] with the default configuration.
+pub fn lint_node(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<PluginDiagnostic> {
+    CairoLint::default().lint_node(db, node)
+}
+
+#[derive(Debug)]
+pub struct CairoLint {
+    config: CairoLintConfig,
+    /// Only set when profiling, the checks aren't timed otherwise.
+    timings: Option<Arc<LintTimings>>,
+}
+
+impl Default for CairoLint {
+    fn default() -> Self {
+        Self::new(CairoLintConfig::default())
+    }
+}
+
+impl CairoLint {
+    pub fn new(config: CairoLintConfig) -> Self {
+        debug_assert_eq!(CairoLintKind::check_registry(), Ok(()));
+        Self { config, timings: None }
+    }
+
+    pub fn with_timings(config: CairoLintConfig, timings: Arc<LintTimings>) -> Self {
+        Self { timings: Some(timings), ..Self::new(config) }
+    }
+
+    /// Lints a node the caller already has, e.g. a snippet or a single function, without going
+    /// through the modules of a crate. Only the checks that don't need the semantic model are run,
+    /// on the node and all of its descendants, so nodes none of them is interested in give nothing.
+    /// Those include the checks of the comments and of the enums, which are run on the items when
+    /// linting a crate.
+    pub fn lint_node(&self, db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<PluginDiagnostic> {
+        let mut diags = Vec::new();
+        timed!(self, comments::check_todo_comments(db, node, &self.config.todo_markers, &mut diags));
+        for node in node.descendants(db) {
+            if node.kind(db) == SyntaxKind::ItemEnum {
+                let item_enum = ItemEnum::from_syntax_node(db, node.clone());
+                timed!(self, naming::check_variant_named_like_enum(db, &item_enum, &mut diags));
+            }
+            self.check_syntax_node(db, node, false, &mut diags);
+        }
+        diags.retain(|diag| {
+            let kind = diagnostic_kind_from_message(&diag.message);
+            self.config.is_enabled(kind)
+                && !(self.config.public_api_only && kind.is_public_api_lint() && !is_in_public_item(db, diag))
+                && !suppressions::is_suppressed(db, diag)
+        });
+        diags
+    }
+
+    fn run_check(&self, name: &'static str, check: impl FnOnce()) {
+        match &self.timings {
+            Some(timings) => {
+                let start = Instant::now();
+                check();
+                timings.record(name, start.elapsed());
+            }
+            None => check(),
+        }
+    }
+
+    /// Runs the checks that only need the syntax on a node. `panic_in_result` is only run when the
+    /// node is known to be part of a function returning a `Result`.
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+        returns_result: bool,
+        diags: &mut Vec<PluginDiagnostic>,
+    ) {
+        match node.kind(db) {
+            SyntaxKind::ExprParenthesized => {
+                timed!(self, double_parens::check_double_parens(db, &AstExpr::from_syntax_node(db, node), diags,))
+            }
+            SyntaxKind::ExprBinary => {
+                let expr_binary = ExprBinary::from_syntax_node(db, node);
+                timed!(self, bool_comparison::check_bool_comparison(db, &expr_binary, diags));
+                timed!(self, double_comparison::check_double_comparison(db, &expr_binary, diags));
+                timed!(self, len_comparison::check_len_comparison(db, &expr_binary, diags));
+                timed!(self, yoda_comparison::check_yoda_comparison(db, &expr_binary, diags));
+            }
+            SyntaxKind::ExprBlock => {
+                let expr_block = ExprBlock::from_syntax_node(db, node);
+                timed!(self, assignments::check_single_reassignment(db, &expr_block, diags));
+                timed!(self, assignments::check_redundant_chain_binding(db, &expr_block, diags));
+            }
+            SyntaxKind::ExprMatch => {
+                let expr_match = ExprMatch::from_syntax_node(db, node);
+                timed!(self, match_arms::check_nested_match(db, &expr_match, diags));
+                timed!(self, match_arms::check_bool_match_wildcard(db, &expr_match, diags));
+                timed!(self, match_arms::check_inverted_bool_match(db, &expr_match, diags));
+                timed!(self, match_arms::check_unused_match_scrutinee(db, &expr_match, diags));
+            }
+            SyntaxKind::StatementBreak => timed!(self, breaks::check_break(db, node, diags)),
+            SyntaxKind::ExprWhile => {
+                let while_expr = ExprWhile::from_syntax_node(db, node);
+                timed!(self, loops::check_infinite_while(db, &while_expr, diags));
+                timed!(self, loops::check_arithmetic_series_loop(db, &while_expr, diags));
+                timed!(self, loops::check_while_let_next(db, &while_expr, diags));
+            }
+            SyntaxKind::WrappedGenericParamList => timed!(
+                self,
+                generics::check_duplicate_bounds(db, &WrappedGenericParamList::from_syntax_node(db, node), diags,)
+            ),
+            SyntaxKind::StatementLet => timed!(
+                self,
+                literals::check_literal_suffix_mismatch(db, &StatementLet::from_syntax_node(db, node), diags)
+            ),
+            SyntaxKind::StatementReturn => {
+                let return_statement = StatementReturn::from_syntax_node(db, node);
+                timed!(self, returns::check_needless_return_block(db, &return_statement, diags));
+                timed!(self, returns::check_needless_unit_return(db, &return_statement, diags));
+            }
+            SyntaxKind::ExprIf => {
+                let expr_if = ExprIf::from_syntax_node(db, node);
+                timed!(self, ifs::check_duplicate_if_bodies(db, &expr_if, diags));
+                timed!(self, ifs::check_if_let_else_panic(db, &expr_if, diags));
+                timed!(self, ifs::check_if_let_chain(db, &expr_if, diags));
+                timed!(self, ifs::check_if_assignments(db, &expr_if, diags));
+                timed!(self, ifs::check_if_same_return(db, &expr_if, diags));
+            }
+            SyntaxKind::ExprInlineMacro => {
+                let inline_macro = ExprInlineMacro::from_syntax_node(db, node.clone());
+                timed!(self, asserts::check_assert_without_message(db, &inline_macro, diags));
+                if returns_result {
+                    timed!(self, panics::check_panic_in_result(db, node, diags))
+                }
+            }
+            SyntaxKind::ExprFunctionCall if returns_result => {
+                timed!(self, panics::check_panic_in_result(db, node, diags))
+            }
+            _ => (),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CairoLintKind {
     DestructMatch,
     MatchForEquality,
@@ -26,77 +202,483 @@ pub enum CairoLintKind {
     Unknown,
     BreakUnit,
     BoolComparison,
+    DuplicateIfBody,
+    RedundantBranchInto,
+    LenComparison,
+    InfiniteRecursion,
+    PanicInResult,
+    InRangeLiteralTryInto,
+    UnreachableWildcard,
+    IfLetElsePanic,
+    UncheckedArithmetic,
+    SingleReassignment,
+    NestedMatch,
+    YodaComparison,
+    LargeStructByValue,
+    BoolMatchWildcard,
+    RedundantChainBinding,
+    InvertedBoolMatch,
+    NeedlessReturnBlock,
+    DefaultComparison,
+    IfAssignments,
+    DuplicateBound,
+    RedundantCloneDerive,
+    UnusedMatchScrutinee,
+    InfiniteWhileLoop,
+    CopyValueIndirection,
+    IfSameReturn,
+    TodoComment,
+    RepeatedMagicNumber,
+    VariantNamedLikeEnum,
+    Felt252Flag,
+    DuplicateFunctionBody,
+    AssertWithoutMessage,
+    SelfComparison,
+    RedundantSnapshot,
+    ArithmeticSeriesLoop,
+    CloneInSnapshotMatch,
+    IfLetChain,
+    LiteralSuffixMismatch,
+    DiscardedCollection,
+    ConstantComparison,
+    WhileLetNext,
+    NeedlessUnitReturn,
+    AlwaysTrueOr,
 }
 
-pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
-    match message {
-        single_match::DESTRUCT_MATCH => CairoLintKind::DestructMatch,
-        single_match::MATCH_FOR_EQUALITY => CairoLintKind::MatchForEquality,
-        double_parens::DOUBLE_PARENS => CairoLintKind::DoubleParens,
-        double_comparison::SIMPLIFIABLE_COMPARISON => CairoLintKind::DoubleComparison,
-        double_comparison::REDUNDANT_COMPARISON => CairoLintKind::DoubleComparison,
-        double_comparison::CONTRADICTORY_COMPARISON => CairoLintKind::DoubleComparison,
-        breaks::BREAK_UNIT => CairoLintKind::BreakUnit,
-        bool_comparison::BOOL_COMPARISON => CairoLintKind::BoolComparison,
-        _ => CairoLintKind::Unknown,
+impl CairoLintKind {
+    /// Every lint, [`CairoLintKind::Unknown`] excluded.
+    pub const ALL: [CairoLintKind; 48] = [
+        CairoLintKind::DestructMatch,
+        CairoLintKind::MatchForEquality,
+        CairoLintKind::DoubleComparison,
+        CairoLintKind::DoubleParens,
+        CairoLintKind::BreakUnit,
+        CairoLintKind::BoolComparison,
+        CairoLintKind::DuplicateIfBody,
+        CairoLintKind::RedundantBranchInto,
+        CairoLintKind::LenComparison,
+        CairoLintKind::InfiniteRecursion,
+        CairoLintKind::PanicInResult,
+        CairoLintKind::InRangeLiteralTryInto,
+        CairoLintKind::UnreachableWildcard,
+        CairoLintKind::IfLetElsePanic,
+        CairoLintKind::UncheckedArithmetic,
+        CairoLintKind::SingleReassignment,
+        CairoLintKind::NestedMatch,
+        CairoLintKind::YodaComparison,
+        CairoLintKind::LargeStructByValue,
+        CairoLintKind::BoolMatchWildcard,
+        CairoLintKind::RedundantChainBinding,
+        CairoLintKind::InvertedBoolMatch,
+        CairoLintKind::NeedlessReturnBlock,
+        CairoLintKind::DefaultComparison,
+        CairoLintKind::IfAssignments,
+        CairoLintKind::DuplicateBound,
+        CairoLintKind::RedundantCloneDerive,
+        CairoLintKind::UnusedMatchScrutinee,
+        CairoLintKind::InfiniteWhileLoop,
+        CairoLintKind::CopyValueIndirection,
+        CairoLintKind::IfSameReturn,
+        CairoLintKind::TodoComment,
+        CairoLintKind::RepeatedMagicNumber,
+        CairoLintKind::VariantNamedLikeEnum,
+        CairoLintKind::Felt252Flag,
+        CairoLintKind::DuplicateFunctionBody,
+        CairoLintKind::AssertWithoutMessage,
+        CairoLintKind::SelfComparison,
+        CairoLintKind::RedundantSnapshot,
+        CairoLintKind::ArithmeticSeriesLoop,
+        CairoLintKind::CloneInSnapshotMatch,
+        CairoLintKind::IfLetChain,
+        CairoLintKind::LiteralSuffixMismatch,
+        CairoLintKind::DiscardedCollection,
+        CairoLintKind::ConstantComparison,
+        CairoLintKind::WhileLetNext,
+        CairoLintKind::NeedlessUnitReturn,
+        CairoLintKind::AlwaysTrueOr,
+    ];
+
+    /// Name of the lint, used to refer to it in the configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CairoLintKind::DestructMatch => "destruct_match",
+            CairoLintKind::MatchForEquality => "match_for_equality",
+            CairoLintKind::DoubleComparison => "double_comparison",
+            CairoLintKind::DoubleParens => "double_parens",
+            CairoLintKind::Unknown => "unknown",
+            CairoLintKind::BreakUnit => "break_unit",
+            CairoLintKind::BoolComparison => "bool_comparison",
+            CairoLintKind::DuplicateIfBody => "duplicate_if_body",
+            CairoLintKind::RedundantBranchInto => "redundant_branch_into",
+            CairoLintKind::LenComparison => "len_comparison",
+            CairoLintKind::InfiniteRecursion => "infinite_recursion",
+            CairoLintKind::PanicInResult => "panic_in_result",
+            CairoLintKind::InRangeLiteralTryInto => "in_range_literal_try_into",
+            CairoLintKind::UnreachableWildcard => "unreachable_wildcard",
+            CairoLintKind::IfLetElsePanic => "if_let_else_panic",
+            CairoLintKind::UncheckedArithmetic => "unchecked_arithmetic",
+            CairoLintKind::SingleReassignment => "single_reassignment",
+            CairoLintKind::NestedMatch => "nested_match",
+            CairoLintKind::YodaComparison => "yoda_comparison",
+            CairoLintKind::LargeStructByValue => "large_struct_by_value",
+            CairoLintKind::BoolMatchWildcard => "bool_match_wildcard",
+            CairoLintKind::RedundantChainBinding => "redundant_chain_binding",
+            CairoLintKind::InvertedBoolMatch => "inverted_bool_match",
+            CairoLintKind::NeedlessReturnBlock => "needless_return_block",
+            CairoLintKind::DefaultComparison => "default_comparison",
+            CairoLintKind::IfAssignments => "if_assignments",
+            CairoLintKind::DuplicateBound => "duplicate_bound",
+            CairoLintKind::RedundantCloneDerive => "redundant_clone_derive",
+            CairoLintKind::UnusedMatchScrutinee => "unused_match_scrutinee",
+            CairoLintKind::InfiniteWhileLoop => "infinite_while_loop",
+            CairoLintKind::CopyValueIndirection => "copy_value_indirection",
+            CairoLintKind::IfSameReturn => "if_same_return",
+            CairoLintKind::TodoComment => "todo_comment",
+            CairoLintKind::RepeatedMagicNumber => "repeated_magic_number",
+            CairoLintKind::VariantNamedLikeEnum => "variant_named_like_enum",
+            CairoLintKind::Felt252Flag => "felt252_flag",
+            CairoLintKind::DuplicateFunctionBody => "duplicate_function_body",
+            CairoLintKind::AssertWithoutMessage => "assert_without_message",
+            CairoLintKind::SelfComparison => "self_comparison",
+            CairoLintKind::RedundantSnapshot => "redundant_snapshot",
+            CairoLintKind::ArithmeticSeriesLoop => "arithmetic_series_loop",
+            CairoLintKind::CloneInSnapshotMatch => "clone_in_snapshot_match",
+            CairoLintKind::IfLetChain => "if_let_chain",
+            CairoLintKind::LiteralSuffixMismatch => "literal_suffix_mismatch",
+            CairoLintKind::DiscardedCollection => "discarded_collection",
+            CairoLintKind::ConstantComparison => "constant_comparison",
+            CairoLintKind::WhileLetNext => "while_let_next",
+            CairoLintKind::NeedlessUnitReturn => "needless_unit_return",
+            CairoLintKind::AlwaysTrueOr => "always_true_or",
+        }
+    }
+
+    /// Messages of the diagnostics of this lint, each of them belonging to a single lint. A
+    /// diagnostic can give details about the code after one of them, separated by a space.
+    pub fn messages(&self) -> &'static [&'static str] {
+        match self {
+            CairoLintKind::DestructMatch => &[single_match::DESTRUCT_MATCH],
+            CairoLintKind::MatchForEquality => &[single_match::MATCH_FOR_EQUALITY],
+            CairoLintKind::DoubleParens => &[double_parens::DOUBLE_PARENS],
+            CairoLintKind::DoubleComparison => &[
+                double_comparison::SIMPLIFIABLE_COMPARISON,
+                double_comparison::REDUNDANT_COMPARISON,
+                double_comparison::CONTRADICTORY_COMPARISON,
+            ],
+            CairoLintKind::BreakUnit => &[breaks::BREAK_UNIT],
+            CairoLintKind::BoolComparison => &[bool_comparison::BOOL_COMPARISON],
+            CairoLintKind::DuplicateIfBody => &[ifs::DUPLICATE_IF_BODY],
+            CairoLintKind::RedundantBranchInto => &[conversions::REDUNDANT_BRANCH_INTO],
+            CairoLintKind::LenComparison => &[len_comparison::LEN_COMPARISON],
+            CairoLintKind::InfiniteRecursion => &[recursion::INFINITE_RECURSION],
+            CairoLintKind::PanicInResult => &[panics::PANIC_IN_RESULT],
+            CairoLintKind::InRangeLiteralTryInto => &[conversions::IN_RANGE_LITERAL_TRY_INTO],
+            CairoLintKind::UnreachableWildcard => &[match_arms::UNREACHABLE_WILDCARD],
+            CairoLintKind::IfLetElsePanic => &[ifs::IF_LET_ELSE_PANIC],
+            CairoLintKind::UncheckedArithmetic => &[arithmetic::UNCHECKED_ARITHMETIC],
+            CairoLintKind::SingleReassignment => &[assignments::SINGLE_REASSIGNMENT],
+            CairoLintKind::NestedMatch => &[match_arms::NESTED_MATCH],
+            CairoLintKind::YodaComparison => &[yoda_comparison::YODA_COMPARISON],
+            CairoLintKind::LargeStructByValue => &[parameters::LARGE_STRUCT_BY_VALUE],
+            CairoLintKind::BoolMatchWildcard => &[match_arms::BOOL_MATCH_WILDCARD],
+            CairoLintKind::RedundantChainBinding => &[assignments::REDUNDANT_CHAIN_BINDING],
+            CairoLintKind::InvertedBoolMatch => &[match_arms::INVERTED_BOOL_MATCH],
+            CairoLintKind::NeedlessReturnBlock => &[returns::NEEDLESS_RETURN_BLOCK],
+            CairoLintKind::DefaultComparison => &[defaults::DEFAULT_COMPARISON],
+            CairoLintKind::IfAssignments => &[ifs::IF_ASSIGNMENTS],
+            CairoLintKind::DuplicateBound => &[generics::DUPLICATE_BOUND],
+            CairoLintKind::RedundantCloneDerive => &[derives::REDUNDANT_CLONE_DERIVE],
+            CairoLintKind::UnusedMatchScrutinee => &[match_arms::UNUSED_MATCH_SCRUTINEE],
+            CairoLintKind::InfiniteWhileLoop => &[loops::INFINITE_WHILE_LOOP],
+            CairoLintKind::CopyValueIndirection => &[parameters::SNAPSHOT_OF_COPY_VALUE, parameters::REF_OF_COPY_VALUE],
+            CairoLintKind::IfSameReturn => &[ifs::IF_SAME_RETURN],
+            CairoLintKind::TodoComment => &[comments::TODO_COMMENT],
+            CairoLintKind::RepeatedMagicNumber => &[magic_numbers::REPEATED_MAGIC_NUMBER],
+            CairoLintKind::VariantNamedLikeEnum => &[naming::VARIANT_NAMED_LIKE_ENUM],
+            CairoLintKind::Felt252Flag => &[flags::FELT252_FLAG],
+            CairoLintKind::DuplicateFunctionBody => &[duplicates::DUPLICATE_FUNCTION_BODY],
+            CairoLintKind::AssertWithoutMessage => &[asserts::ASSERT_WITHOUT_MESSAGE],
+            CairoLintKind::SelfComparison => &[self_comparison::SELF_COMPARISON],
+            CairoLintKind::RedundantSnapshot => &[snapshots::REDUNDANT_SNAPSHOT],
+            CairoLintKind::ArithmeticSeriesLoop => &[loops::ARITHMETIC_SERIES_LOOP],
+            CairoLintKind::CloneInSnapshotMatch => &[snapshots::CLONE_IN_SNAPSHOT_MATCH],
+            CairoLintKind::IfLetChain => &[ifs::IF_LET_CHAIN],
+            CairoLintKind::LiteralSuffixMismatch => &[literals::LITERAL_SUFFIX_MISMATCH],
+            CairoLintKind::DiscardedCollection => &[collections::DISCARDED_COLLECTION],
+            CairoLintKind::ConstantComparison => &[bounds::CONSTANT_COMPARISON],
+            CairoLintKind::WhileLetNext => &[loops::WHILE_LET_NEXT],
+            CairoLintKind::NeedlessUnitReturn => &[returns::NEEDLESS_UNIT_RETURN],
+            CairoLintKind::AlwaysTrueOr => {
+                &[tautologies::ALWAYS_TRUE_OR_SKIPPED_OPERAND, tautologies::ALWAYS_TRUE_OR_IGNORED_OPERAND]
+            }
+            CairoLintKind::Unknown => &[],
+        }
+    }
+
+    /// Checks that every lint has messages and that no two lints share a name or a message, or have
+    /// a message starting with the message of another one, which would make the configuration or
+    /// the diagnostics ambiguous. Gives the first duplicate found.
+    pub fn check_registry() -> Result<(), String> {
+        let mut names = HashSet::from([CairoLintKind::Unknown.name()]);
+        let mut messages: HashMap<&str, CairoLintKind> = HashMap::new();
+        for kind in Self::ALL {
+            if !names.insert(kind.name()) {
+                return Err(format!("The name `{}` is used by several lints", kind.name()));
+            }
+            if kind.messages().is_empty() {
+                return Err(format!("The lint `{}` has no message", kind.name()));
+            }
+            for message in kind.messages() {
+                if let Some(other) = messages.insert(*message, kind) {
+                    return Err(format!(
+                        "The message \"{message}\" is used by both `{}` and `{}`",
+                        other.name(),
+                        kind.name()
+                    ));
+                }
+            }
+        }
+        for (message, kind) in &messages {
+            if let Some((other_message, other)) =
+                messages.iter().find(|(other_message, _)| other_message.starts_with(&format!("{message} ")))
+            {
+                return Err(format!(
+                    "The message \"{other_message}\" of `{}` starts with the message of `{}`",
+                    other.name(),
+                    kind.name()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the lint with the given name, as in the configuration.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// Severity of the diagnostics of this lint.
+    pub fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Whether this lint only concerns the public api of a crate. These lints are skipped on items
+    /// that aren't `pub` when [`CairoLintConfig::public_api_only`] is enabled.
+    pub fn is_public_api_lint(&self) -> bool {
+        matches!(self, CairoLintKind::LargeStructByValue | CairoLintKind::VariantNamedLikeEnum)
+    }
+
+    /// Whether this lint reports conversions. These lints are skipped on the arguments of the calls
+    /// to `extern` functions unless [`CairoLintConfig::lint_extern_call_arguments`] is enabled.
+    pub fn is_conversion_lint(&self) -> bool {
+        matches!(self, CairoLintKind::RedundantBranchInto | CairoLintKind::InRangeLiteralTryInto)
+    }
+
+    /// Whether this lint is run when it isn't listed in the configuration. Lints that are often
+    /// wrong about the code being a mistake have to be enabled explicitly.
+    pub fn is_enabled_by_default(&self) -> bool {
+        !matches!(
+            self,
+            CairoLintKind::PanicInResult
+                | CairoLintKind::UncheckedArithmetic
+                | CairoLintKind::YodaComparison
+                | CairoLintKind::LargeStructByValue
+                | CairoLintKind::RedundantCloneDerive
+                | CairoLintKind::TodoComment
+                | CairoLintKind::RepeatedMagicNumber
+                | CairoLintKind::VariantNamedLikeEnum
+                | CairoLintKind::Felt252Flag
+                | CairoLintKind::DuplicateFunctionBody
+                | CairoLintKind::AssertWithoutMessage
+                | CairoLintKind::ArithmeticSeriesLoop
+        )
     }
 }
 
+/// Gets the lint of a diagnostic from its message, which is one of the messages of the lint
+/// possibly followed by details about the code.
+pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
+    let is_lint_message = |lint_message: &&str| {
+        message.strip_prefix(*lint_message).is_some_and(|details| details.is_empty() || details.starts_with(' '))
+    };
+    CairoLintKind::ALL
+        .into_iter()
+        .find(|kind| kind.messages().iter().any(is_lint_message))
+        .unwrap_or(CairoLintKind::Unknown)
+}
+
 impl AnalyzerPlugin for CairoLint {
     fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
         let mut diags = Vec::new();
-        let Ok(free_functions_ids) = db.module_free_functions_ids(module_id) else {
-            return diags;
-        };
-        for free_func_id in free_functions_ids.iter() {
+        // The overrides of the configuration are matched against the file of the module.
+        let file_path =
+            db.module_main_file(module_id).map(|file_id| file_id.full_path(db.upcast())).unwrap_or_default();
+        // Going through the whole crate for each module is only worth it when the lint is enabled.
+        if self.config.is_enabled_for_file(CairoLintKind::RepeatedMagicNumber, &file_path) {
+            timed!(
+                self,
+                magic_numbers::check_repeated_magic_numbers(
+                    db,
+                    module_id,
+                    self.config.magic_number_threshold,
+                    &self.config.ignored_magic_numbers,
+                    &mut diags
+                )
+            );
+        }
+        if self.config.is_enabled_for_file(CairoLintKind::DuplicateFunctionBody, &file_path) {
+            timed!(
+                self,
+                duplicates::check_duplicate_function_bodies(
+                    db,
+                    module_id,
+                    self.config.duplicate_body_min_statements,
+                    &mut diags
+                )
+            );
+        }
+        // The diagnostics found so far still go through the filtering below when the module or a
+        // function can't be analyzed.
+        let free_functions_ids = db.module_free_functions_ids(module_id);
+        for free_func_id in free_functions_ids.iter().flat_map(|ids| ids.iter()) {
             let Ok(function_body) = db.function_body(FunctionWithBodyId::Free(*free_func_id)) else {
-                return diags;
+                continue;
             };
-            for (_expression_id, expression) in &function_body.arenas.exprs {
+            let arenas = &function_body.arenas;
+            let function_diags_start = diags.len();
+            for (_expression_id, expression) in &arenas.exprs {
                 match &expression {
                     Expr::Match(expr_match) => {
-                        single_match::check_single_match(db, expr_match, &mut diags, &function_body.arenas)
+                        timed!(self, single_match::check_single_match(db, expr_match, &mut diags, arenas));
+                        timed!(self, match_arms::check_unreachable_wildcard(db, expr_match, &mut diags, arenas));
                     }
                     Expr::Loop(expr_loop) => {
-                        loops::check_loop_match_pop_front(db, expr_loop, &mut diags, &function_body.arenas)
+                        timed!(self, loops::check_loop_match_pop_front(db, expr_loop, &mut diags, arenas))
+                    }
+                    Expr::If(expr_if) => {
+                        timed!(self, conversions::check_redundant_branch_into(db, expr_if, &mut diags, arenas));
+                        timed!(self, flags::check_felt252_flag(db, expr_if, &mut diags, arenas));
+                    }
+                    Expr::Snapshot(expr_snapshot) => {
+                        timed!(self, snapshots::check_redundant_snapshot(db, expr_snapshot, &mut diags, arenas));
+                        timed!(self, snapshots::check_clone_in_snapshot_match(db, expr_snapshot, &mut diags, arenas));
+                    }
+                    Expr::LogicalOperator(logical_operator) => {
+                        timed!(self, tautologies::check_always_true_or(db, logical_operator, &mut diags, arenas))
+                    }
+                    Expr::FunctionCall(expr_func_call) => {
+                        timed!(
+                            self,
+                            conversions::check_in_range_literal_try_into(db, expr_func_call, &mut diags, arenas)
+                        );
+                        timed!(self, arithmetic::check_unchecked_arithmetic(db, expr_func_call, &mut diags, arenas));
+                        timed!(self, defaults::check_default_comparison(db, expr_func_call, &mut diags, arenas));
+                        timed!(self, self_comparison::check_self_comparison(db, expr_func_call, &mut diags, arenas));
+                        timed!(self, bounds::check_constant_comparison(db, expr_func_call, &mut diags, arenas));
+                        timed!(
+                            self,
+                            parameters::check_copy_value_indirection(
+                                db,
+                                *free_func_id,
+                                expr_func_call,
+                                &mut diags,
+                                arenas
+                            )
+                        );
                     }
                     _ => (),
                 };
             }
+            // Low-level code converts the arguments of libfuncs on purpose.
+            if !self.config.lint_extern_call_arguments {
+                let arguments = conversions::extern_call_arguments(db, arenas);
+                let function_diags = diags.split_off(function_diags_start);
+                diags.extend(function_diags.into_iter().filter(|diag| {
+                    !(diagnostic_kind_from_message(&diag.message).is_conversion_lint()
+                        && conversions::is_in_extern_call_argument(db.upcast(), diag, &arguments))
+                }));
+            }
+            for (_statement_id, statement) in &arenas.statements {
+                if let Statement::Expr(statement_expr) = statement {
+                    timed!(self, collections::check_discarded_collection(db, statement_expr, &mut diags, arenas));
+                }
+            }
+            timed!(
+                self,
+                recursion::check_infinite_recursion(db, *free_func_id, function_body.body_expr, &mut diags, arenas)
+            );
+            timed!(
+                self,
+                parameters::check_large_struct_by_value(
+                    db,
+                    *free_func_id,
+                    self.config.large_struct_threshold,
+                    &mut diags
+                )
+            );
         }
         let syntax_db = db.upcast();
-        let Ok(items) = db.module_items(module_id) else {
-            return diags;
-        };
-        for item in &*items {
-            let function_nodes = match item {
+        let items = db.module_items(module_id);
+        for item in items.iter().flat_map(|items| items.iter()) {
+            // Inline submodules have their items linted as a module of their own.
+            if !matches!(item, ModuleItemId::Submodule(_)) {
+                let item_node = item.untyped_stable_ptr(db.upcast()).lookup(syntax_db);
+                timed!(
+                    self,
+                    comments::check_todo_comments(syntax_db, &item_node, &self.config.todo_markers, &mut diags)
+                );
+            }
+            let (item_node, returns_result) = match item {
                 ModuleItemId::Constant(constant_id) => {
-                    constant_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node()
-                }
-                ModuleItemId::FreeFunction(free_function_id) => {
-                    free_function_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node()
+                    (constant_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node(), false)
                 }
-                _ => continue,
-            }
-            .descendants(syntax_db);
-
-            for node in function_nodes {
-                match node.kind(syntax_db) {
-                    SyntaxKind::ExprParenthesized => double_parens::check_double_parens(
-                        db.upcast(),
-                        &AstExpr::from_syntax_node(db.upcast(), node),
-                        &mut diags,
-                    ),
-                    SyntaxKind::ExprBinary => {
-                        let expr_binary = ExprBinary::from_syntax_node(db.upcast(), node);
-                        bool_comparison::check_bool_comparison(db.upcast(), &expr_binary, &mut diags);
-                        double_comparison::check_double_comparison(db.upcast(), &expr_binary, &mut diags);
+                ModuleItemId::FreeFunction(free_function_id) => (
+                    free_function_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node(),
+                    panics::returns_result(db, *free_function_id),
+                ),
+                // Only the definitions of the types are linted, not their bodies.
+                ModuleItemId::Struct(_) | ModuleItemId::Enum(_) => {
+                    timed!(self, derives::check_redundant_clone_derive(db, *item, &mut diags));
+                    if let ModuleItemId::Enum(enum_id) = item {
+                        let item_enum = enum_id.stable_ptr(db.upcast()).lookup(syntax_db);
+                        timed!(self, naming::check_variant_named_like_enum(syntax_db, &item_enum, &mut diags));
                     }
-                    SyntaxKind::StatementBreak => breaks::check_break(db.upcast(), node, &mut diags),
-                    _ => continue,
+                    continue;
                 }
+                _ => continue,
+            };
+            for node in item_node.descendants(syntax_db) {
+                self.check_syntax_node(syntax_db, node, returns_result, &mut diags);
             }
         }
+        diags.retain(|diag| {
+            let kind = diagnostic_kind_from_message(&diag.message);
+            self.config.is_enabled_for_file(kind, &file_path)
+                && !(self.config.public_api_only && kind.is_public_api_lint() && !is_in_public_item(syntax_db, diag))
+                && !suppressions::is_suppressed(syntax_db, diag)
+        });
         diags
     }
 }
+
+/// Checks if an item is part of the public api of its crate, `pub(crate)` items being excluded.
+fn is_public(db: &dyn SyntaxGroup, visibility: &Visibility) -> bool {
+    visibility.as_syntax_node().get_text_without_trivia(db) == "pub"
+}
+
+/// Checks if the closest item around the node of a diagnostic is part of the public api of its
+/// crate. Diagnostics outside of any item are kept as if they were.
+fn is_in_public_item(db: &dyn SyntaxGroup, diag: &PluginDiagnostic) -> bool {
+    for node in iter::successors(Some(diag.stable_ptr.lookup(db)), |node| node.parent()) {
+        let visibility = match node.kind(db) {
+            SyntaxKind::FunctionWithBody => FunctionWithBody::from_syntax_node(db, node).visibility(db),
+            SyntaxKind::ItemEnum => ItemEnum::from_syntax_node(db, node).visibility(db),
+            SyntaxKind::ItemStruct => ItemStruct::from_syntax_node(db, node).visibility(db),
+            SyntaxKind::ItemConstant => ItemConstant::from_syntax_node(db, node).visibility(db),
+            _ => continue,
+        };
+        return is_public(db, &visibility);
+    }
+    true
+}
@@ -0,0 +1,52 @@
+//! Time spent in each lint, to find the ones slowing the linter down.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Total time spent in each check of the lints, shared by the plugins of a run.
+#[derive(Debug, Default)]
+pub struct LintTimings {
+    checks: Mutex<HashMap<&'static str, CheckTiming>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CheckTiming {
+    /// Time spent in the check over all the runs.
+    pub total: Duration,
+    /// Number of times the check ran.
+    pub runs: usize,
+}
+
+impl LintTimings {
+    /// Records one run of a check.
+    pub fn record(&self, check: &'static str, elapsed: Duration) {
+        let mut checks = self.checks.lock().unwrap();
+        let timing = checks.entry(check).or_default();
+        timing.total += elapsed;
+        timing.runs += 1;
+    }
+
+    /// Gets the timing of each check, the slowest first.
+    pub fn sorted(&self) -> Vec<(&'static str, CheckTiming)> {
+        let mut checks: Vec<_> = self.checks.lock().unwrap().iter().map(|(check, timing)| (*check, *timing)).collect();
+        checks.sort_by(|(first_check, first), (second_check, second)| {
+            second.total.cmp(&first.total).then(first_check.cmp(second_check))
+        });
+        checks
+    }
+}
+
+/// Table of the timings, one check per line, the slowest first.
+impl fmt::Display for LintTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let checks = self.sorted();
+        let width = checks.iter().map(|(check, _)| check.len()).max().unwrap_or_default().max("check".len());
+        writeln!(f, "{:<width$}  {:>12}  {:>8}", "check", "total", "runs")?;
+        for (check, timing) in checks {
+            writeln!(f, "{check:<width$}  {:>12}  {:>8}", format!("{:.3?}", timing.total), timing.runs)?;
+        }
+        Ok(())
+    }
+}
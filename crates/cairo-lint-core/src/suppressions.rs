@@ -0,0 +1,53 @@
+//! Suppression of diagnostics with comment directives, for the places where an attribute can't be
+//! put, e.g. inside an expression:
+//!
+//! ```cairo
+//! match x {
+//!     true => 1,
+//!     false => 0,
+//!     // cairo-lint: allow bool_match_wildcard
+//!     _ => 2,
+//! }
+//! ```
+//!
+//! A directive applies to the line that follows it. It can name several lints, separated by
+//! commas, or none to suppress every lint.
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+
+use crate::plugin::{diagnostic_kind_from_message, CairoLintKind};
+
+const DIRECTIVE: &str = "cairo-lint: allow";
+
+/// Checks if a diagnostic is suppressed by a directive on the line preceding it.
+pub fn is_suppressed(db: &dyn SyntaxGroup, diagnostic: &PluginDiagnostic) -> bool {
+    let file_id = diagnostic.stable_ptr.file_id(db);
+    let offset = diagnostic.stable_ptr.lookup(db).span_start_without_trivia(db);
+    let (Some(position), Some(content)) = (offset.position_in_file(db.upcast(), file_id), db.file_content(file_id))
+    else {
+        return false;
+    };
+    let Some(previous_line) = position.line.checked_sub(1).and_then(|line| content.lines().nth(line)) else {
+        return false;
+    };
+    allowed_lints(previous_line)
+        .is_some_and(|lints| lints.is_empty() || lints.contains(&diagnostic_kind_from_message(&diagnostic.message)))
+}
+
+/// Parses the lints allowed by a directive line, an empty list allowing every lint. Unknown lint
+/// names are ignored.
+fn allowed_lints(line: &str) -> Option<Vec<CairoLintKind>> {
+    let names = line.trim().strip_prefix("//")?.trim().strip_prefix(DIRECTIVE)?;
+    // `// cairo-lint: allowed` isn't a directive.
+    if !names.is_empty() && !names.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let names = names.split(',').map(str::trim).filter(|name| !name.is_empty()).collect::<Vec<_>>();
+    if names.is_empty() {
+        return Some(Vec::new());
+    }
+    // Only naming unknown lints mustn't suppress everything.
+    let lints = names.into_iter().filter_map(CairoLintKind::from_name).collect::<Vec<_>>();
+    if lints.is_empty() { None } else { Some(lints) }
+}
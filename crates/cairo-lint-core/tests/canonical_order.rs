@@ -0,0 +1,76 @@
+//! Checks that the diagnostics are in the same order and without duplicates whatever the order in
+//! which they were produced.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::diagnostics::canonicalize_diagnostics;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::{setup_crate, CRATE_ROOT};
+use pretty_assertions::assert_eq;
+
+fn setup() -> (RootDatabase, CrateId) {
+    let files = [
+        ("lib.cairo", "mod other;\n\nfn main() -> u32 {\n    ((1)) + ((2))\n}\n"),
+        ("other.cairo", "fn other() {\n    loop {\n        break ();\n    }\n}\n"),
+    ];
+    setup_crate(CRATE_ROOT, &files, cairo_lint_plugin_suite())
+}
+
+/// Gets the diagnostics of the modules of a crate in the order the compiler produced them.
+fn produced_diagnostics(db: &RootDatabase, crate_id: CrateId) -> Vec<SemanticDiagnostic> {
+    db.crate_modules(crate_id)
+        .iter()
+        .flat_map(|module_id| db.module_semantic_diagnostics(*module_id).unwrap().get_all())
+        .collect()
+}
+
+/// Gets the file name and the start offset of each diagnostic.
+fn locations(db: &RootDatabase, diagnostics: &[SemanticDiagnostic]) -> Vec<(String, usize)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let location = diagnostic.location(db.upcast());
+            (location.file_id.file_name(db.upcast()), location.span.to_str_range().start)
+        })
+        .collect()
+}
+
+#[test]
+fn diagnostics_are_sorted_by_file_and_span() {
+    let (db, crate_id) = setup();
+    let mut diagnostics = produced_diagnostics(&db, crate_id);
+    diagnostics.reverse();
+
+    let lib_start = "mod other;\n\nfn main() -> u32 {\n    ".len();
+    assert_eq!(
+        locations(&db, &canonicalize_diagnostics(&db, diagnostics)),
+        [
+            ("lib.cairo".to_owned(), lib_start),
+            ("lib.cairo".to_owned(), lib_start + "((1)) + ".len()),
+            ("other.cairo".to_owned(), "fn other() {\n    loop {\n        ".len()),
+        ]
+    );
+}
+
+#[test]
+fn shuffled_and_duplicated_diagnostics_give_the_same_order() {
+    let (db, crate_id) = setup();
+    let canonical = canonicalize_diagnostics(&db, produced_diagnostics(&db, crate_id));
+    assert_eq!(canonical.len(), 3);
+
+    let mut shuffled = produced_diagnostics(&db, crate_id);
+    shuffled.rotate_left(1);
+    shuffled.extend(produced_diagnostics(&db, crate_id).into_iter().rev());
+    shuffled.push(canonical[1].clone());
+    let messages = |diagnostics: &[SemanticDiagnostic]| {
+        diagnostics.iter().map(|diagnostic| diagnostic.format(&db)).collect::<Vec<_>>()
+    };
+    let shuffled = canonicalize_diagnostics(&db, shuffled);
+    assert_eq!(locations(&db, &shuffled), locations(&db, &canonical));
+    assert_eq!(messages(&shuffled), messages(&canonical));
+}
@@ -0,0 +1,96 @@
+//! Checks the precedence of the configuration layers, merged key by key.
+
+use cairo_lint_core::config::{CairoLintConfig, CairoLintConfigLayer};
+use cairo_lint_core::plugin::CairoLintKind;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn layer(value: serde_json::Value) -> CairoLintConfigLayer {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn no_layer_gives_the_default_configuration() {
+    assert_eq!(CairoLintConfig::merged([]), CairoLintConfig::default());
+    assert_eq!(CairoLintConfig::merged([layer(json!({}))]), CairoLintConfig::default());
+}
+
+#[test]
+fn each_layer_only_replaces_the_keys_it_sets() {
+    let workspace = layer(json!({
+        "large-struct-threshold": 4,
+        "max-diagnostics": 10,
+        "lints": { "double_parens": false, "break_unit": false },
+    }));
+    let package = layer(json!({
+        "max-diagnostics": 20,
+        "todo-markers": ["HACK"],
+        "lints": { "break_unit": true },
+    }));
+    let command_line = CairoLintConfigLayer { deny_warnings: Some(true), ..CairoLintConfigLayer::default() };
+
+    let config = CairoLintConfig::merged([workspace, package, command_line]);
+    assert_eq!(config.large_struct_threshold, 4);
+    assert_eq!(config.max_diagnostics, Some(20));
+    assert_eq!(config.todo_markers, ["HACK"]);
+    assert!(config.deny_warnings);
+    assert!(!config.is_enabled(CairoLintKind::DoubleParens));
+    assert!(config.is_enabled(CairoLintKind::BreakUnit));
+    // Set by no layer.
+    assert_eq!(config.magic_number_threshold, CairoLintConfig::default().magic_number_threshold);
+    assert!(config.is_enabled(CairoLintKind::BoolComparison));
+}
+
+#[test]
+fn later_layers_take_precedence() {
+    let workspace = layer(json!({ "default": false, "max-diagnostics": 10 }));
+    let package = layer(json!({ "default": true }));
+    let command_line = CairoLintConfigLayer { max_diagnostics: Some(5), ..CairoLintConfigLayer::default() };
+
+    let config = CairoLintConfig::merged([workspace.clone(), package.clone(), command_line]);
+    assert!(config.default);
+    assert_eq!(config.max_diagnostics, Some(5));
+    let config = CairoLintConfig::merged([package, workspace]);
+    assert!(!config.default);
+}
+
+#[test]
+fn overrides_of_later_layers_win_between_equally_specific_ones() {
+    let workspace = layer(json!({
+        "overrides": [{ "path": "src/contracts/**", "lints": { "double_parens": false, "break_unit": false } }],
+    }));
+    let package = layer(json!({
+        "overrides": [{ "path": "src/contracts/**", "lints": { "double_parens": true } }],
+    }));
+
+    let config = CairoLintConfig::merged([workspace, package]);
+    assert_eq!(config.overrides.len(), 2);
+    assert!(config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/vault.cairo"));
+    assert!(!config.is_enabled_for_file(CairoLintKind::BreakUnit, "/pkg/src/contracts/vault.cairo"));
+}
+
+#[test]
+fn layer_is_read_from_the_manifest_of_a_virtual_workspace() {
+    let manifest = r#"
+[workspace]
+members = ["contracts", "utils"]
+
+[tool.cairo-lint]
+max-diagnostics = 10
+lints = { double_parens = false }
+"#;
+    let workspace = CairoLintConfigLayer::from_manifest(manifest).unwrap();
+    assert_eq!(workspace, layer(json!({ "max-diagnostics": 10, "lints": { "double_parens": false } })));
+
+    let config = CairoLintConfig::merged([workspace, layer(json!({ "lints": { "break_unit": false } }))]);
+    assert_eq!(config.max_diagnostics, Some(10));
+    assert!(!config.is_enabled(CairoLintKind::DoubleParens));
+    assert!(!config.is_enabled(CairoLintKind::BreakUnit));
+}
+
+#[test]
+fn manifest_without_the_section_sets_no_key() {
+    let manifest = "[workspace]\nmembers = [\"contracts\"]\n\n[tool.other-tool]\nkey = 1\n";
+    assert_eq!(CairoLintConfigLayer::from_manifest(manifest).unwrap(), CairoLintConfigLayer::default());
+    assert!(CairoLintConfigLayer::from_manifest("[tool.cairo-lint]\nmax-diagnostics = \"ten\"\n").is_err());
+}
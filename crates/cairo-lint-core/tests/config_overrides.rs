@@ -0,0 +1,58 @@
+//! Checks the precedence of the per path overrides of the configuration.
+
+use std::collections::BTreeMap;
+
+use cairo_lint_core::config::{CairoLintConfig, LintOverride};
+use cairo_lint_core::plugin::CairoLintKind;
+
+fn lint_override(path: &str, lints: &[(&str, bool)]) -> LintOverride {
+    LintOverride {
+        path: path.to_string(),
+        lints: lints.iter().map(|(name, enabled)| (name.to_string(), *enabled)).collect(),
+    }
+}
+
+fn config_with(overrides: Vec<LintOverride>) -> CairoLintConfig {
+    CairoLintConfig {
+        lints: BTreeMap::from([("double_parens".to_string(), false)]),
+        overrides,
+        ..CairoLintConfig::default()
+    }
+}
+
+#[test]
+fn files_without_matching_override_use_the_global_configuration() {
+    let config = config_with(vec![lint_override("src/contracts/**", &[("double_parens", true)])]);
+    assert!(!config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/utils/math.cairo"));
+    assert!(config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/vault.cairo"));
+    assert!(config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/erc20/token.cairo"));
+    // Only whole components are matched.
+    assert!(!config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/my_contracts/vault.cairo"));
+}
+
+#[test]
+fn most_specific_override_wins() {
+    let config = config_with(vec![
+        lint_override("src/contracts/vault.cairo", &[("double_parens", false)]),
+        lint_override("src/**", &[("double_parens", true), ("break_unit", false)]),
+        lint_override("src/contracts/*.cairo", &[("double_parens", true)]),
+    ]);
+    assert!(!config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/vault.cairo"));
+    assert!(config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/token.cairo"));
+    assert!(config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/lib.cairo"));
+    // Overrides that don't list a lint don't change it.
+    assert!(!config.is_enabled_for_file(CairoLintKind::BreakUnit, "/pkg/src/contracts/vault.cairo"));
+    assert!(config.is_enabled_for_file(CairoLintKind::BoolComparison, "/pkg/src/contracts/vault.cairo"));
+}
+
+#[test]
+fn last_override_wins_between_equally_specific_ones() {
+    let enabling = lint_override("**/vault.cairo", &[("double_parens", true)]);
+    let disabling = lint_override("src/contract?/**", &[("double_parens", false)]);
+    assert_eq!(enabling.specificity(), disabling.specificity());
+
+    let config = config_with(vec![enabling.clone(), disabling.clone()]);
+    assert!(!config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/vault.cairo"));
+    let config = config_with(vec![disabling, enabling]);
+    assert!(config.is_enabled_for_file(CairoLintKind::DoubleParens, "/pkg/src/contracts/vault.cairo"));
+}
@@ -0,0 +1,91 @@
+//! Checks that limiting the diagnostics keeps the earliest ones and tells how many were left out.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::diagnostics::{crate_diagnostics, limit_diagnostics, truncation_notice};
+use cairo_lint_core::lints::double_parens::DOUBLE_PARENS;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::setup_single_file_crate;
+use pretty_assertions::assert_eq;
+
+/// Sets up a crate with a `double_parens` diagnostic on each of its 4 lines of code.
+fn setup() -> (RootDatabase, CrateId) {
+    let content =
+        "fn main() -> u32 {\n    let a = ((1));\n    let b = ((2));\n    let c = ((3));\n    ((a + b + c))\n}\n";
+    setup_single_file_crate(content, cairo_lint_plugin_suite())
+}
+
+/// Gets the messages of the diagnostics and the line each one starts on.
+fn messages(db: &RootDatabase, crate_id: CrateId, max_diagnostics: Option<usize>) -> Vec<(String, usize)> {
+    let diagnostics = crate_diagnostics(db, crate_id);
+    limit_diagnostics(diagnostics, max_diagnostics)
+        .map(|diag| {
+            let location = diag.location(db.upcast());
+            let line = location.span.position_in_file(db.upcast(), location.file_id).unwrap().start.line;
+            (diag.format(db), line)
+        })
+        .collect()
+}
+
+/// Gets the severity and the message of the notice of the diagnostics of some code limited to one.
+fn notice_of(content: &str) -> (Severity, String) {
+    let (db, crate_id) = setup_single_file_crate(content, cairo_lint_plugin_suite());
+    let notice = limit_diagnostics(crate_diagnostics(&db, crate_id), Some(1)).nth(1).unwrap();
+    (notice.severity(), notice.format(&db))
+}
+
+fn plugin_message(message: &str) -> String {
+    format!("Plugin diagnostic: {message}")
+}
+
+#[test]
+fn everything_is_reported_without_limit() {
+    let (db, crate_id) = setup();
+    let lines: Vec<_> = messages(&db, crate_id, None).into_iter().map(|(_, line)| line).collect();
+    assert_eq!(lines, [1, 2, 3, 4]);
+}
+
+#[test]
+fn the_earliest_diagnostics_are_kept() {
+    let (db, crate_id) = setup();
+    assert_eq!(
+        messages(&db, crate_id, Some(2)),
+        [
+            (plugin_message(DOUBLE_PARENS), 1),
+            (plugin_message(DOUBLE_PARENS), 2),
+            (plugin_message(&truncation_notice(2)), 3),
+        ]
+    );
+}
+
+#[test]
+fn no_notice_when_nothing_is_left_out() {
+    let (db, crate_id) = setup();
+    assert_eq!(messages(&db, crate_id, Some(4)).len(), 4);
+}
+
+#[test]
+fn a_limit_of_zero_only_reports_the_notice() {
+    let (db, crate_id) = setup();
+    assert_eq!(messages(&db, crate_id, Some(0)), [(plugin_message(&truncation_notice(4)), 1)]);
+}
+
+#[test]
+fn a_single_diagnostic_left_out_is_told_in_the_singular() {
+    assert_eq!(
+        truncation_notice(1),
+        "1 more diagnostic wasn't reported, starting with this one. Raise `max-diagnostics` to see them."
+    );
+    assert_eq!(
+        notice_of("fn main() -> u32 {\n    let a = ((1));\n    ((a))\n}\n"),
+        (Severity::Warning, plugin_message(&truncation_notice(1)))
+    );
+}
+
+#[test]
+fn the_notice_is_an_error_when_an_error_is_left_out() {
+    let content = "fn main() -> u32 {\n    let a = ((1));\n    let b = ((2));\n    missing_function() + a + b\n}\n";
+    assert_eq!(notice_of(content), (Severity::Error, plugin_message(&truncation_notice(2))));
+}
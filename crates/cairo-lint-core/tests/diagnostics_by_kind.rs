@@ -0,0 +1,64 @@
+//! Checks the grouping of the diagnostics of a crate by lint kind.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::diagnostics::{crate_diagnostics, crate_diagnostics_by_kind, group_diagnostics_by_kind};
+use cairo_lint_core::plugin::{cairo_lint_plugin_suite, CairoLintKind};
+use cairo_lint_test_utils::setup_single_file_crate;
+use pretty_assertions::assert_eq;
+
+const CONTENT: &str = "fn main() -> u32 {
+    ((1)) + ((2))
+}
+
+fn unknown() -> u32 {
+    missing_function()
+}
+
+fn same(a: u32) -> bool {
+    a == a
+}
+";
+
+/// Gets the 0-based line each diagnostic starts on.
+fn lines(db: &RootDatabase, diagnostics: &[SemanticDiagnostic]) -> Vec<usize> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let location = diagnostic.location(db.upcast());
+            location.span.position_in_file(db.upcast(), location.file_id).unwrap().start.line
+        })
+        .collect()
+}
+
+#[test]
+fn diagnostics_are_grouped_by_lint_kind() {
+    let (db, crate_id) = setup_single_file_crate(CONTENT, cairo_lint_plugin_suite());
+    let groups = crate_diagnostics_by_kind(&db, crate_id);
+
+    let mut kinds: Vec<_> = groups.keys().map(|kind| kind.name()).collect();
+    kinds.sort();
+    assert_eq!(kinds, ["double_parens", "self_comparison", "unknown"]);
+    assert_eq!(lines(&db, &groups[&CairoLintKind::DoubleParens]), [1, 1]);
+    assert_eq!(lines(&db, &groups[&CairoLintKind::SelfComparison]), [9]);
+    // The diagnostics of the compiler don't come from any lint.
+    let unknown = &groups[&CairoLintKind::Unknown];
+    assert_eq!(lines(&db, unknown), [5]);
+    assert!(unknown[0].format(&db).starts_with("Function not found."));
+}
+
+#[test]
+fn groups_are_in_the_canonical_order() {
+    let (db, crate_id) = setup_single_file_crate(CONTENT, cairo_lint_plugin_suite());
+    let mut reversed = crate_diagnostics(&db, crate_id);
+    reversed.reverse();
+    let groups = group_diagnostics_by_kind(&db, reversed);
+
+    let double_parens = &groups[&CairoLintKind::DoubleParens];
+    let starts: Vec<_> =
+        double_parens.iter().map(|diagnostic| diagnostic.location(db.upcast()).span.to_str_range().start).collect();
+    let main_start = "fn main() -> u32 {\n    ".len();
+    assert_eq!(starts, [main_start, main_start + "((1)) + ".len()]);
+}
@@ -0,0 +1,81 @@
+//! Checks that the lints which aren't enabled in the configuration report nothing, including the
+//! ones going through the whole module or crate.
+
+use std::collections::BTreeMap;
+
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lint_core::config::{CairoLintConfig, LintOverride};
+use cairo_lint_core::diagnostics::crate_diagnostics;
+use cairo_lint_core::lints::self_comparison::SELF_COMPARISON;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite_with_config;
+use cairo_lint_test_utils::setup_single_file_crate;
+use pretty_assertions::assert_eq;
+
+/// Triggers lints of the syntax, of the function bodies, of the module and of the crate.
+const CONTENT: &str = "fn first(value: u32) -> bool {
+    let doubled = value * 42;
+    ((doubled == doubled))
+}
+
+fn second(value: u32) -> bool {
+    let doubled = value * 42;
+    ((doubled == doubled))
+}
+
+fn third(value: u32) -> u32 {
+    value * 42
+}
+";
+
+/// Gets the messages of the diagnostics of the linter with a configuration.
+fn lint_messages(config: CairoLintConfig) -> Vec<String> {
+    let (db, crate_id) = setup_single_file_crate(CONTENT, cairo_lint_plugin_suite_with_config(config));
+    crate_diagnostics(&db, crate_id)
+        .into_iter()
+        .filter_map(|diagnostic| match diagnostic.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) => Some(plugin_diagnostic.message),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn nothing_is_reported_without_default_nor_listed_lints() {
+    assert!(!lint_messages(CairoLintConfig::default()).is_empty());
+    assert_eq!(lint_messages(CairoLintConfig { default: false, ..CairoLintConfig::default() }), Vec::<String>::new());
+}
+
+#[test]
+fn only_the_listed_lints_are_reported_without_default() {
+    let config = CairoLintConfig {
+        default: false,
+        lints: BTreeMap::from([("self_comparison".to_string(), true)]),
+        ..CairoLintConfig::default()
+    };
+    assert_eq!(lint_messages(config), [SELF_COMPARISON, SELF_COMPARISON]);
+}
+
+#[test]
+fn unknown_lint_names_are_found() {
+    let config = CairoLintConfig {
+        lints: BTreeMap::from([("deny_parens".to_string(), true), ("double_parens".to_string(), false)]),
+        overrides: vec![LintOverride {
+            path: "src/contracts/**".to_string(),
+            lints: BTreeMap::from([("deny_parens".to_string(), false), ("self_comparsion".to_string(), true)]),
+        }],
+        ..CairoLintConfig::default()
+    };
+    assert_eq!(config.unknown_lints(), ["deny_parens", "self_comparsion"]);
+    assert_eq!(CairoLintConfig::default().unknown_lints(), Vec::<&str>::new());
+}
+
+#[test]
+fn unknown_lint_names_dont_count_as_enabled_lints() {
+    let config_with = |name: &str| CairoLintConfig {
+        default: false,
+        lints: BTreeMap::from([(name.to_string(), true)]),
+        ..CairoLintConfig::default()
+    };
+    assert!(config_with("deny_parens").disables_all_lints());
+    assert!(!config_with("double_parens").disables_all_lints());
+}
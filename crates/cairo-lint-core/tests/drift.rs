@@ -0,0 +1,66 @@
+//! Checks the drift reported between the expected and the current rendered diagnostics.
+
+use cairo_lint_test_utils::diagnostics_drift;
+use pretty_assertions::assert_eq;
+
+const EXPECTED: &str = "warning: Plugin diagnostic: unnecessary double parentheses found. Consider removing them.
+ --> lib.cairo:2:5
+  |
+2 |     ((1_u32))
+  |     ---------
+  |
+warning: Plugin diagnostic: This value is compared to itself.
+ --> lib.cairo:6:5
+  |
+6 |     a == a
+  |     ------
+  |";
+
+#[test]
+fn same_diagnostics_have_no_drift() {
+    assert_eq!(diagnostics_drift(EXPECTED, EXPECTED), Vec::<String>::new());
+    assert_eq!(diagnostics_drift("", ""), Vec::<String>::new());
+}
+
+#[test]
+fn changed_messages_and_spans_are_reported_at_their_location() {
+    let actual = EXPECTED.replace("This value is compared to itself.", "Comparing a value to itself.").replacen(
+        "((1_u32))\n  |     ---------",
+        "((1_u32))\n  |      -------",
+        1,
+    );
+    assert_eq!(
+        diagnostics_drift(EXPECTED, &actual),
+        [
+            "span changed at lib.cairo:2:5: warning: Plugin diagnostic: unnecessary double parentheses found. \
+             Consider removing them.",
+            "message changed at lib.cairo:6:5: warning: Plugin diagnostic: This value is compared to itself. -> \
+             warning: Plugin diagnostic: Comparing a value to itself.",
+        ]
+    );
+}
+
+#[test]
+fn moved_new_and_gone_diagnostics_are_reported() {
+    let actual = "warning: Plugin diagnostic: This value is compared to itself.
+ --> lib.cairo:7:5
+  |
+7 |     a == a
+  |     ------
+  |
+error: Plugin diagnostic: Leftover `todo!()`.
+ --> lib.cairo:9:5
+  |
+9 |     todo!()
+  |     ^^^^^^^
+  |";
+    assert_eq!(
+        diagnostics_drift(EXPECTED, actual),
+        [
+            "moved from lib.cairo:6:5 to lib.cairo:7:5: warning: Plugin diagnostic: This value is compared to itself.",
+            "new at lib.cairo:9:5: error: Plugin diagnostic: Leftover `todo!()`.",
+            "gone from lib.cairo:2:5: warning: Plugin diagnostic: unnecessary double parentheses found. Consider \
+             removing them.",
+        ]
+    );
+}
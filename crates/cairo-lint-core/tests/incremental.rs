@@ -0,0 +1,84 @@
+//! Checks that the lints of a module are only recomputed when the module itself changes, so editors
+//! never get stale diagnostics nor wait for the lints of untouched modules.
+
+use std::sync::{Arc, Mutex};
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::diagnostics::crate_diagnostics;
+use cairo_lint_core::plugin::CairoLint;
+use cairo_lint_test_utils::{set_file, setup_crate, CRATE_ROOT};
+use pretty_assertions::assert_eq;
+
+/// Runs the lints while recording the modules they're run on.
+#[derive(Debug)]
+struct CountingLint {
+    lint: CairoLint,
+    linted_modules: Arc<Mutex<Vec<String>>>,
+}
+
+impl AnalyzerPlugin for CountingLint {
+    fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
+        self.linted_modules.lock().unwrap().push(module_id.full_path(db.upcast()));
+        self.lint.diagnostics(db, module_id)
+    }
+}
+
+fn setup(linted_modules: Arc<Mutex<Vec<String>>>) -> (RootDatabase, CrateId) {
+    let mut suite = PluginSuite::default();
+    suite.add_analyzer_plugin_ex(Arc::new(CountingLint { lint: CairoLint::default(), linted_modules }));
+    let files = [
+        ("lib.cairo", "mod first;\nmod second;\n"),
+        ("first.cairo", "fn first() -> u32 {\n    ((1))\n}\n"),
+        ("second.cairo", "fn second() -> u32 {\n    2\n}\n"),
+    ];
+    setup_crate(CRATE_ROOT, &files, suite)
+}
+
+/// Gets the messages of the diagnostics of a module, identified by its file name.
+fn module_messages(db: &RootDatabase, diagnostics: &[SemanticDiagnostic], file_name: &str) -> Vec<String> {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.location(db.upcast()).file_id.file_name(db.upcast()) == file_name)
+        .map(|diagnostic| diagnostic.format(db))
+        .collect()
+}
+
+#[test]
+fn only_the_changed_module_is_linted_again() {
+    let linted_modules = Arc::new(Mutex::new(Vec::new()));
+    let (mut db, crate_id) = setup(linted_modules.clone());
+
+    let diagnostics = crate_diagnostics(&db, crate_id);
+    let mut initially_linted = std::mem::take(&mut *linted_modules.lock().unwrap());
+    initially_linted.sort();
+    assert_eq!(initially_linted, ["test_crate", "test_crate::first", "test_crate::second"]);
+    let first_messages = module_messages(&db, &diagnostics, "first.cairo");
+    assert_eq!(first_messages.len(), 1);
+    assert!(module_messages(&db, &diagnostics, "second.cairo").is_empty());
+
+    set_file(&mut db, CRATE_ROOT, "second.cairo", "fn second() -> u32 {\n    ((2))\n}\n");
+    let diagnostics = crate_diagnostics(&db, crate_id);
+    assert_eq!(*linted_modules.lock().unwrap(), ["test_crate::second"]);
+    assert_eq!(module_messages(&db, &diagnostics, "first.cairo"), first_messages);
+    assert_eq!(module_messages(&db, &diagnostics, "second.cairo").len(), 1);
+}
+
+#[test]
+fn unchanged_crate_is_not_linted_again() {
+    let linted_modules = Arc::new(Mutex::new(Vec::new()));
+    let (db, crate_id) = setup(linted_modules.clone());
+
+    let diagnostics = crate_diagnostics(&db, crate_id);
+    linted_modules.lock().unwrap().clear();
+    let relinted_diagnostics = crate_diagnostics(&db, crate_id);
+    assert!(linted_modules.lock().unwrap().is_empty());
+    assert_eq!(diagnostics, relinted_diagnostics);
+}
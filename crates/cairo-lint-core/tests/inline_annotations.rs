@@ -0,0 +1,96 @@
+//! Checks the matching of the diagnostics of some code with its inline annotations.
+
+use cairo_lint_core::diagnostics::crate_diagnostics;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::{check_inline_annotations, setup_single_file_crate};
+use pretty_assertions::assert_eq;
+
+/// Checks the diagnostics of a crate made of the given code against its annotations.
+fn check(code: &str) -> Result<(), String> {
+    let (db, crate_id) = setup_single_file_crate(code, cairo_lint_plugin_suite());
+    check_inline_annotations(&db, code, &crate_diagnostics(&db, crate_id))
+}
+
+#[test]
+fn matching_annotations_pass() {
+    let code = "fn main() -> u32 {
+    ((1))
+    //~^ WARNING unnecessary double parentheses
+}
+";
+    assert_eq!(check(code), Ok(()));
+}
+
+#[test]
+fn annotation_without_diagnostic_fails() {
+    let code = "fn main() -> u32 {
+    ((1)) //~ WARNING unnecessary double parentheses
+}
+
+fn other() -> u32 {
+    1 //~ WARNING unnecessary double parentheses
+}
+";
+    assert_eq!(
+        check(code),
+        Err("no diagnostic for annotation on line 6: Warning unnecessary double parentheses".to_string())
+    );
+}
+
+#[test]
+fn unexpected_diagnostic_fails() {
+    let code = "fn main() -> u32 {
+    ((1)) //~ WARNING unnecessary double parentheses
+}
+
+fn other() -> u32 {
+    ((2))
+}
+";
+    assert_eq!(
+        check(code),
+        Err("unexpected diagnostic on line 6: Plugin diagnostic: unnecessary double parentheses found. Consider \
+             removing them."
+            .to_string())
+    );
+}
+
+#[test]
+fn annotation_of_another_severity_fails() {
+    let code = "fn main() -> u32 {
+    ((1)) //~ ERROR unnecessary double parentheses
+}
+";
+    let error = check(code).unwrap_err();
+    assert!(error.contains("unexpected diagnostic on line 2"), "{error}");
+    assert!(error.contains("no diagnostic for annotation on line 2: Error"), "{error}");
+}
+
+#[test]
+fn annotation_on_the_wrong_line_fails() {
+    let code = "fn main() -> bool {
+    let a = array![1_u32, 2];
+    let b = array![3_u32];
+    a.len() != b.len()
+    //~^^ WARNING Comparison between the lengths of two collections
+}
+";
+    let error = check(code).unwrap_err();
+    assert!(error.contains("unexpected diagnostic on line 4"), "{error}");
+    assert!(error.contains("no diagnostic for annotation on line 3"), "{error}");
+}
+
+#[test]
+fn diagnostic_without_annotation_fails() {
+    let code = "fn main() -> bool {
+    let a = array![1_u32, 2];
+    let b = array![3_u32];
+    let smaller = a.len() < b.len(); //~ WARNING Comparison between the lengths of two collections
+    let larger = a.len() > b.len();
+    smaller || larger
+}
+";
+    let error = check(code).unwrap_err();
+    assert!(error.starts_with("unexpected diagnostic on line 5: "), "{error}");
+    assert!(!error.contains("line 4"), "{error}");
+}
@@ -0,0 +1,70 @@
+//! Checks that the JSON Lines output is made of independently parseable lines with the same shape
+//! as the elements of the JSON output, both starting with the ruleset.
+
+use cairo_lint_core::config::CairoLintConfig;
+use cairo_lint_core::diagnostics::{
+    crate_diagnostics, stream_crate_diagnostics, write_json, write_json_lines, Ruleset,
+};
+use cairo_lint_core::VERSION;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::{setup_crate, setup_single_file_crate, CRATE_ROOT};
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+
+#[test]
+fn json_lines_match_the_json_array() {
+    let files = [
+        ("lib.cairo", "mod other;\n\nfn main() -> u32 {\n    ((1))\n}\n"),
+        ("other.cairo", "fn other() {\n    loop {\n        break ();\n    }\n}\n"),
+    ];
+    let (db, crate_id) = setup_crate(CRATE_ROOT, &files, cairo_lint_plugin_suite());
+
+    let ruleset = Ruleset::new(&CairoLintConfig::default());
+    let mut array_output = Vec::new();
+    write_json(&db, &crate_diagnostics(&db, crate_id), None, &ruleset, &mut array_output).unwrap();
+    let mut array_output: Value = serde_json::from_slice(&array_output).unwrap();
+    assert_eq!(array_output["version"], VERSION);
+    assert_eq!(array_output["ruleset_hash"], ruleset.ruleset_hash);
+    let Value::Array(mut array_elements) = array_output["diagnostics"].take() else {
+        panic!("The diagnostics of the JSON output aren't an array");
+    };
+
+    let mut lines_output = Vec::new();
+    write_json_lines(&db, stream_crate_diagnostics(&db, crate_id), None, &ruleset, &mut lines_output).unwrap();
+    let mut line_elements = String::from_utf8(lines_output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    let header = line_elements.remove(0);
+    assert_eq!(header, serde_json::json!({ "version": VERSION, "ruleset_hash": ruleset.ruleset_hash }));
+
+    assert_eq!(line_elements.len(), 2);
+    let sort_key = |element: &Value| element["lint"].to_string();
+    array_elements.sort_by_key(sort_key);
+    line_elements.sort_by_key(sort_key);
+    assert_eq!(line_elements, array_elements);
+    assert_eq!(line_elements[0]["lint"], "break_unit");
+    assert_eq!(line_elements[0]["line"], 3);
+    assert_eq!(line_elements[1]["lint"], "double_parens");
+    assert_eq!(line_elements[1]["column"], 5);
+}
+
+#[test]
+fn ruleset_is_written_without_diagnostics() {
+    let (db, _) = setup_single_file_crate("fn main() {}\n", cairo_lint_plugin_suite());
+    let ruleset = Ruleset::new(&CairoLintConfig::default());
+    let header = serde_json::json!({ "version": VERSION, "ruleset_hash": ruleset.ruleset_hash });
+
+    let mut array_output = Vec::new();
+    write_json(&db, &[], None, &ruleset, &mut array_output).unwrap();
+    let mut expected = header.clone();
+    expected["diagnostics"] = serde_json::json!([]);
+    assert_eq!(serde_json::from_slice::<Value>(&array_output).unwrap(), expected);
+
+    let mut lines_output = Vec::new();
+    write_json_lines(&db, [], None, &ruleset, &mut lines_output).unwrap();
+    let lines_output = String::from_utf8(lines_output).unwrap();
+    let lines = lines_output.lines().map(|line| serde_json::from_str::<Value>(line).unwrap()).collect::<Vec<_>>();
+    assert_eq!(lines, [header]);
+}
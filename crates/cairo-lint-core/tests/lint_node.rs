@@ -0,0 +1,59 @@
+//! Checks that nodes given by the caller are linted without going through the modules of a crate.
+
+use std::collections::BTreeMap;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_semantic::plugin::PluginSuite;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::config::CairoLintConfig;
+use cairo_lint_core::lints::comments::TODO_COMMENT;
+use cairo_lint_core::lints::double_parens::DOUBLE_PARENS;
+use cairo_lint_core::lints::naming::VARIANT_NAMED_LIKE_ENUM;
+use cairo_lint_core::plugin::{lint_node, CairoLint};
+use cairo_lint_test_utils::setup_single_file_crate;
+use pretty_assertions::assert_eq;
+
+/// Sets up a crate made of the given code, along with the node of its only item. The linter isn't
+/// run as a plugin, so that only [`lint_node`] reports diagnostics.
+fn item_node(content: &str) -> (RootDatabase, SyntaxNode) {
+    let (db, crate_id) = setup_single_file_crate(content, PluginSuite::default());
+    let items = db.module_items(ModuleId::CrateRoot(crate_id)).unwrap();
+    let [item] = &items[..] else {
+        panic!("The crate doesn't have exactly one item");
+    };
+    let node = item.untyped_stable_ptr(db.upcast()).lookup(db.upcast());
+    (db, node)
+}
+
+#[test]
+fn descendants_of_the_node_are_linted() {
+    let (db, node) = item_node("fn main() -> u32 {\n    ((1))\n}\n");
+
+    let messages: Vec<_> = lint_node(db.upcast(), &node).into_iter().map(|diag| diag.message).collect();
+    assert_eq!(messages, [DOUBLE_PARENS]);
+}
+
+#[test]
+fn nodes_without_checks_give_nothing() {
+    let (db, node) = item_node("fn main() -> u32 {\n    ((1))\n}\n");
+    // The name of the function is a terminal, none of its descendants is checked.
+    let name = node.descendants(db.upcast()).find(|node| node.get_text_without_trivia(db.upcast()) == "main").unwrap();
+
+    assert!(lint_node(db.upcast(), &name).is_empty());
+}
+
+#[test]
+fn comments_and_enums_are_linted() {
+    let (db, node) = item_node("// TODO: add more shapes.\nenum Shape {\n    Shape: u32,\n    Circle: u32,\n}\n");
+    let config = CairoLintConfig {
+        lints: BTreeMap::from([("todo_comment".to_string(), true), ("variant_named_like_enum".to_string(), true)]),
+        ..CairoLintConfig::default()
+    };
+
+    let messages: Vec<_> =
+        CairoLint::new(config).lint_node(db.upcast(), &node).into_iter().map(|diag| diag.message).collect();
+    assert_eq!(messages, [TODO_COMMENT, VARIANT_NAMED_LIKE_ENUM]);
+}
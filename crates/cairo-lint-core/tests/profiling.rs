@@ -0,0 +1,26 @@
+//! Checks that every check of the lints that runs is timed when profiling.
+
+use std::sync::Arc;
+
+use cairo_lint_core::config::CairoLintConfig;
+use cairo_lint_core::diagnostics::crate_diagnostics;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite_with_timings;
+use cairo_lint_core::profiling::LintTimings;
+use cairo_lint_test_utils::setup_single_file_crate;
+
+#[test]
+fn checks_are_timed() {
+    let timings = Arc::new(LintTimings::default());
+    let (db, crate_id) = setup_single_file_crate(
+        "fn main() -> u32 {\n    ((1)) + ((2))\n}\n",
+        cairo_lint_plugin_suite_with_timings(CairoLintConfig::default(), timings.clone()),
+    );
+
+    assert_eq!(crate_diagnostics(&db, crate_id).len(), 2);
+    let checks = timings.sorted();
+    let double_parens = checks.iter().find(|(check, _)| *check == "double_parens::check_double_parens").unwrap();
+    // The outer parentheses of both operands and the inner ones.
+    assert_eq!(double_parens.1.runs, 4);
+    assert!(checks.iter().any(|(check, _)| *check == "recursion::check_infinite_recursion"));
+    assert!(timings.to_string().starts_with("check"));
+}
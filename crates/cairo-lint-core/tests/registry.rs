@@ -0,0 +1,38 @@
+//! Checks that the lints can't be mistaken for one another, from the configuration or from their
+//! diagnostics.
+
+use cairo_lint_core::lints::conversions::IN_RANGE_LITERAL_TRY_INTO;
+use cairo_lint_core::plugin::{diagnostic_kind_from_message, CairoLintKind};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn lints_have_unique_names_and_messages() {
+    assert_eq!(CairoLintKind::check_registry(), Ok(()));
+}
+
+#[test]
+fn lints_are_found_from_their_names() {
+    for kind in CairoLintKind::ALL {
+        assert_eq!(CairoLintKind::from_name(kind.name()), Some(kind));
+    }
+    assert_eq!(CairoLintKind::from_name(CairoLintKind::Unknown.name()), None);
+}
+
+#[test]
+fn lints_are_found_from_their_messages() {
+    for kind in CairoLintKind::ALL {
+        for message in kind.messages() {
+            assert_eq!(diagnostic_kind_from_message(message), kind, "{message}");
+        }
+    }
+    assert_eq!(diagnostic_kind_from_message("Unused variable."), CairoLintKind::Unknown);
+}
+
+#[test]
+fn lints_are_found_from_their_messages_with_details() {
+    let message = format!("{IN_RANGE_LITERAL_TRY_INTO} Consider writing `1_u8`.");
+    assert_eq!(diagnostic_kind_from_message(&message), CairoLintKind::InRangeLiteralTryInto);
+    // The details are separated from the message of the lint.
+    let message = format!("{IN_RANGE_LITERAL_TRY_INTO}.");
+    assert_eq!(diagnostic_kind_from_message(&message), CairoLintKind::Unknown);
+}
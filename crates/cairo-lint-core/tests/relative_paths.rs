@@ -0,0 +1,33 @@
+//! Checks that the files of the diagnostics are written relative to the base path containing them.
+
+use std::path::Path;
+
+use cairo_lint_core::diagnostics::{crate_diagnostics, relative_path, JsonDiagnostic};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::setup_crate;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+/// Nested in `/repo`, the base path of the diagnostics.
+const CRATE_ROOT: &str = "/repo/crates/relative_paths";
+
+#[test_case("/repo/a/src/lib.cairo", None, "/repo/a/src/lib.cairo"; "without base path")]
+#[test_case("/repo/a/src/lib.cairo", Some("/repo"), "a/src/lib.cairo"; "nested directory")]
+#[test_case("/repo/a/src/lib.cairo", Some("/repo/a/"), "src/lib.cairo"; "base path with a trailing slash")]
+#[test_case("/repo/a/src/lib.cairo", Some("/repo/b"), "/repo/a/src/lib.cairo"; "sibling directory")]
+#[test_case("/repo/ab/src/lib.cairo", Some("/repo/a"), "/repo/ab/src/lib.cairo"; "sibling directory with a prefix")]
+fn files_are_relative_to_the_base_path(path: &str, base_path: Option<&str>, expected: &str) {
+    assert_eq!(relative_path(path, base_path.map(Path::new)), expected);
+}
+
+#[test]
+fn json_diagnostics_are_relative_to_the_base_path() {
+    let (db, crate_id) =
+        setup_crate(CRATE_ROOT, &[("lib.cairo", "fn main() -> u32 {\n    ((1))\n}\n")], cairo_lint_plugin_suite());
+
+    let files: Vec<_> = crate_diagnostics(&db, crate_id)
+        .iter()
+        .map(|diagnostic| JsonDiagnostic::new(&db, diagnostic, Some(Path::new("/repo"))).file)
+        .collect();
+    assert_eq!(files, ["crates/relative_paths/lib.cairo"]);
+}
@@ -0,0 +1,77 @@
+//! Checks the review output against a golden JSON, the suggestions replacing whole lines.
+
+use std::path::Path;
+
+use cairo_lint_core::diagnostics::{crate_diagnostics, write_review, Ruleset};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::{setup_crate, setup_single_file_crate, CRATE_ROOT};
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+
+const GOLDEN: &str = r#"{
+  "version": "0.0.0",
+  "ruleset_hash": "0123456789abcdef",
+  "diagnostics": [
+    {
+      "rule": "double_parens",
+      "severity": "warning",
+      "message": "Plugin diagnostic: unnecessary double parentheses found. Consider removing them.",
+      "file": "lib.cairo",
+      "line": 4,
+      "end_line": 4,
+      "suggestion": { "line": 4, "end_line": 4, "replacement": "    1" }
+    },
+    {
+      "rule": "break_unit",
+      "severity": "warning",
+      "message": "Plugin diagnostic: unnecessary double parentheses found after break. Consider removing them.",
+      "file": "other.cairo",
+      "line": 3,
+      "end_line": 3,
+      "suggestion": { "line": 3, "end_line": 3, "replacement": "        break;" }
+    },
+    {
+      "rule": "self_comparison",
+      "severity": "warning",
+      "message": "Plugin diagnostic: This value is compared to itself, so the result is always the same. Consider comparing it to another value.",
+      "file": "other.cairo",
+      "line": 8,
+      "end_line": 8,
+      "suggestion": null
+    }
+  ]
+}"#;
+
+#[test]
+fn review_output_matches_the_golden_json() {
+    let files = [
+        ("lib.cairo", "mod other;\n\nfn main() -> u32 {\n    ((1))\n}\n"),
+        (
+            "other.cairo",
+            "fn other() {\n    loop {\n        break ();\n    }\n}\n\nfn same(a: u32) -> bool {\n    a == a\n}\n",
+        ),
+    ];
+    let (db, crate_id) = setup_crate(CRATE_ROOT, &files, cairo_lint_plugin_suite());
+
+    let ruleset = Ruleset { version: "0.0.0", ruleset_hash: "0123456789abcdef".to_string() };
+    let mut output = Vec::new();
+    write_review(&db, &crate_diagnostics(&db, crate_id), Some(Path::new(CRATE_ROOT)), &ruleset, &mut output).unwrap();
+    let mut output: Value = serde_json::from_slice(&output).unwrap();
+    let Value::Array(diagnostics) = &mut output["diagnostics"] else {
+        panic!("The diagnostics of the review output aren't an array");
+    };
+    diagnostics.sort_by_key(|diagnostic| (diagnostic["file"].to_string(), diagnostic["line"].as_u64()));
+    assert_eq!(output, serde_json::from_str::<Value>(GOLDEN).unwrap());
+}
+
+#[test]
+fn review_output_has_the_ruleset_without_diagnostics() {
+    let (db, _) = setup_single_file_crate("fn main() {}\n", cairo_lint_plugin_suite());
+    let ruleset = Ruleset { version: "0.0.0", ruleset_hash: "0123456789abcdef".to_string() };
+    let mut output = Vec::new();
+    write_review(&db, &[], None, &ruleset, &mut output).unwrap();
+    assert_eq!(
+        serde_json::from_slice::<Value>(&output).unwrap(),
+        serde_json::json!({ "version": "0.0.0", "ruleset_hash": "0123456789abcdef", "diagnostics": [] })
+    );
+}
@@ -0,0 +1,47 @@
+//! Checks that the hash of the ruleset only changes with the lints that are run.
+
+use cairo_lint_core::config::CairoLintConfig;
+use cairo_lint_core::diagnostics::Ruleset;
+use cairo_lint_core::VERSION;
+use pretty_assertions::{assert_eq, assert_ne};
+
+fn config_with(lints: &[(&str, bool)]) -> CairoLintConfig {
+    CairoLintConfig {
+        lints: lints.iter().map(|(name, enabled)| (name.to_string(), *enabled)).collect(),
+        ..CairoLintConfig::default()
+    }
+}
+
+#[test]
+fn equal_configurations_have_the_same_hash() {
+    assert_eq!(CairoLintConfig::default().ruleset_hash(), CairoLintConfig::default().ruleset_hash());
+    assert_eq!(
+        config_with(&[("double_parens", false), ("yoda_comparison", true)]).ruleset_hash(),
+        config_with(&[("yoda_comparison", true), ("double_parens", false)]).ruleset_hash()
+    );
+    // Only what is run matters, not how the configuration says it.
+    assert_eq!(config_with(&[("double_parens", true)]).ruleset_hash(), CairoLintConfig::default().ruleset_hash());
+    // Not running the lints doesn't change them.
+    let config = CairoLintConfig { deny_warnings: true, max_diagnostics: Some(1), ..CairoLintConfig::default() };
+    assert_eq!(config.ruleset_hash(), CairoLintConfig::default().ruleset_hash());
+}
+
+#[test]
+fn toggling_a_lint_changes_the_hash() {
+    let default_hash = CairoLintConfig::default().ruleset_hash();
+    assert_ne!(config_with(&[("double_parens", false)]).ruleset_hash(), default_hash);
+    assert_ne!(config_with(&[("yoda_comparison", true)]).ruleset_hash(), default_hash);
+    assert_ne!(
+        config_with(&[("double_parens", false)]).ruleset_hash(),
+        config_with(&[("yoda_comparison", true)]).ruleset_hash()
+    );
+}
+
+#[test]
+fn ruleset_identifies_the_version_and_the_hash() {
+    let config = config_with(&[("double_parens", false)]);
+    let ruleset = Ruleset::new(&config);
+    assert_eq!(ruleset.version, VERSION);
+    assert_eq!(ruleset.ruleset_hash, format!("{:016x}", config.ruleset_hash()));
+    assert_eq!(ruleset.to_string(), format!("cairo-lint {VERSION} ({})", ruleset.ruleset_hash));
+}
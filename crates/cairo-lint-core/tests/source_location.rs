@@ -0,0 +1,125 @@
+//! Checks the resolution of the diagnostics and of the pointers of plugin diagnostics to their
+//! location in the file.
+
+use std::path::PathBuf;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_diagnostics::{DiagnosticEntry, DiagnosticLocation};
+use cairo_lang_filesystem::ids::{CrateId, FileId};
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::diagnostics::{crate_diagnostics, SourceLocation};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::{setup_single_file_crate, CRATE_ROOT};
+use pretty_assertions::assert_eq;
+
+const CONTENT: &str = "fn main() -> u32 {
+    ((1))
+}
+
+fn block() -> u32 {
+    return {
+        let x = 1;
+        x + 1
+    };
+}
+";
+
+fn setup(content: &str) -> (RootDatabase, CrateId) {
+    setup_single_file_crate(content, cairo_lint_plugin_suite())
+}
+
+fn root_file(db: &RootDatabase) -> FileId {
+    FileId::new(db.upcast(), PathBuf::from(CRATE_ROOT).join("lib.cairo"))
+}
+
+fn lint_location(db: &RootDatabase, crate_id: CrateId, message_start: &str) -> SourceLocation {
+    let diagnostic = crate_diagnostics(db, crate_id)
+        .into_iter()
+        .find(|diagnostic| diagnostic.format(db).starts_with(message_start))
+        .unwrap();
+    SourceLocation::new(db, &diagnostic.location(db.upcast())).unwrap()
+}
+
+#[test]
+fn single_line_span() {
+    let (db, crate_id) = setup(CONTENT);
+    let start = CONTENT.find("((1))").unwrap();
+    assert_eq!(
+        lint_location(&db, crate_id, "Plugin diagnostic: unnecessary double parentheses"),
+        SourceLocation {
+            file_path: format!("{CRATE_ROOT}/lib.cairo"),
+            start: (1, 4),
+            end: (1, 9),
+            byte_range: start..start + "((1))".len(),
+        }
+    );
+}
+
+#[test]
+fn multi_line_span() {
+    let (db, crate_id) = setup(CONTENT);
+    let start = CONTENT.find("return {").unwrap();
+    let end = CONTENT.find("};").unwrap() + "};".len();
+    assert_eq!(
+        lint_location(&db, crate_id, "Plugin diagnostic: This `return` of a block"),
+        SourceLocation {
+            file_path: format!("{CRATE_ROOT}/lib.cairo"),
+            start: (5, 4),
+            end: (8, 6),
+            byte_range: start..end
+        }
+    );
+}
+
+#[test]
+fn plugin_pointers_resolve_to_the_location_of_their_diagnostic() {
+    let (db, crate_id) = setup(CONTENT);
+    let diagnostics = crate_diagnostics(&db, crate_id);
+    assert!(!diagnostics.is_empty());
+    for diagnostic in diagnostics {
+        let SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) = &diagnostic.kind else {
+            continue;
+        };
+        assert_eq!(
+            SourceLocation::from_stable_ptr(&db, plugin_diagnostic.stable_ptr),
+            SourceLocation::new(&db, &diagnostic.location(db.upcast()))
+        );
+    }
+}
+
+#[test]
+fn zero_width_span() {
+    let (db, crate_id) = setup("fn main() {}\n");
+    let function_id = db.module_free_functions_ids(ModuleId::CrateRoot(crate_id)).unwrap()[0];
+    let function = function_id.stable_ptr(db.upcast()).lookup(db.upcast());
+    // A function without a return type has an empty return type clause after its parameters.
+    let return_type = function.declaration(db.upcast()).signature(db.upcast()).ret_ty(db.upcast());
+    let offset = "fn main() ".len();
+    assert_eq!(
+        SourceLocation::from_stable_ptr(&db, return_type.stable_ptr().untyped()),
+        Some(SourceLocation {
+            file_path: format!("{CRATE_ROOT}/lib.cairo"),
+            start: (0, offset),
+            end: (0, offset),
+            byte_range: offset..offset,
+        })
+    );
+}
+
+#[test]
+fn unresolvable_locations_give_none() {
+    let (db, _) = setup("fn main() {}\n");
+    let span =
+        TextSpan { start: TextOffset::default(), end: TextOffset::default().add_width(TextWidth::from_str("fn")) };
+    let missing_file = FileId::new(db.upcast(), PathBuf::from(CRATE_ROOT).join("missing.cairo"));
+    assert_eq!(SourceLocation::new(&db, &DiagnosticLocation { file_id: missing_file, span }), None);
+
+    let past_the_end =
+        TextSpan { start: TextOffset::default(), end: TextOffset::default().add_width(TextWidth::from_str(CONTENT)) };
+    assert_eq!(SourceLocation::new(&db, &DiagnosticLocation { file_id: root_file(&db), span: past_the_end }), None);
+}
@@ -0,0 +1,48 @@
+//! Checks when a run fails, the policy every front-end relies on for its exit code.
+
+use cairo_lint_core::config::CairoLintConfig;
+use cairo_lint_core::diagnostics::{crate_diagnostics, LintSummary};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::setup_single_file_crate;
+use test_case::test_case;
+
+#[test_case(0, 0, false, false; "clean run")]
+#[test_case(0, 0, true, false; "clean run denying warnings")]
+#[test_case(0, 2, false, false; "warnings only")]
+#[test_case(0, 2, true, true; "warnings only denying warnings")]
+#[test_case(1, 0, false, false; "errors only")]
+#[test_case(1, 0, true, true; "errors only denying warnings")]
+#[test_case(1, 2, false, false; "errors and warnings")]
+#[test_case(1, 2, true, true; "errors and warnings denying warnings")]
+fn run_fails(errors: usize, warnings: usize, deny_warnings: bool, should_fail: bool) {
+    let summary = LintSummary::from_counts(errors, warnings, deny_warnings);
+    assert_eq!(summary, LintSummary { errors, warnings, should_fail });
+}
+
+#[test]
+fn merged_run_fails_if_any_run_does() {
+    // Warnings are only denied for the first package.
+    let first = LintSummary::from_counts(0, 1, true);
+    let second = LintSummary::from_counts(0, 3, false);
+
+    assert_eq!(first.merge(second), LintSummary { errors: 0, warnings: 4, should_fail: true });
+    assert!(!second.merge(LintSummary::default()).should_fail);
+}
+
+/// The compiler errors are counted like the ones of the lints, as the front-ends do.
+#[test_case(false, false; "default policy")]
+#[test_case(true, true; "denying warnings")]
+fn compiler_errors_only_fail_when_denied(deny_warnings: bool, should_fail: bool) {
+    let (db, crate_id) =
+        setup_single_file_crate("fn main() -> u32 {\n    missing_function()\n}\n", cairo_lint_plugin_suite());
+    let config = CairoLintConfig { deny_warnings, ..CairoLintConfig::default() };
+    let summary = LintSummary::new(crate_diagnostics(&db, crate_id), &config);
+    assert_eq!(summary, LintSummary { errors: 1, warnings: 0, should_fail });
+}
+
+#[test_case(0, 0, "0 errors, 0 warnings"; "clean run")]
+#[test_case(1, 1, "1 error, 1 warning"; "single diagnostics")]
+#[test_case(2, 3, "2 errors, 3 warnings"; "several diagnostics")]
+fn summary_is_displayed_as_counts(errors: usize, warnings: usize, expected: &str) {
+    assert_eq!(LintSummary::from_counts(errors, warnings, false).to_string(), expected);
+}
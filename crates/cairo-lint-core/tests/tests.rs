@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::sync::{LazyLock, Mutex};
 
@@ -13,11 +13,12 @@ use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_test_utils::parse_test_file::{dump_to_test_file, parse_test_file, Test};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
+use cairo_lint_core::config::CairoLintConfig;
 use cairo_lint_core::diagnostics::format_diagnostic;
 use cairo_lint_core::fix::{apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix};
-use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_core::plugin::cairo_lint_plugin_suite_with_config;
 use cairo_lint_test_utils::{get_diags, test_file, Tests};
-use ctor::dtor;
+use ctor::{ctor, dtor};
 use itertools::Itertools;
 use paste::paste;
 use pretty_assertions::assert_eq;
@@ -31,6 +32,16 @@ negative_impls = true
 coupons = true
 "#;
 
+/// Reports the differences between the expectations of all the fixtures and what the lints
+/// currently produce, see [`cairo_lint_test_utils::fixtures_drift`]. Run on demand to review the
+/// changes of the lints, the README shows how.
+#[test]
+#[ignore = "drift report of the fixtures, run on demand"]
+fn fixtures_drift() {
+    let report = cairo_lint_test_utils::fixtures_drift();
+    assert!(report.is_empty(), "the fixtures drifted:\n{}", report.join("\n"));
+}
+
 test_file!(unused_variables, unused_variables, "one unused variable", "two unused variable", "plenty unused variables");
 
 test_file!(
@@ -110,3 +121,413 @@ test_file!(
     "Negated comparison with false",
     "Negated comparison with false on LHS"
 );
+
+test_file!(
+    ifs,
+    duplicate_if_bodies,
+    "different else if bodies",
+    "duplicate else body",
+    "duplicate else if body",
+    "duplicate else if body after another branch"
+);
+
+test_file!(
+    conversions,
+    redundant_branch_into,
+    "branches with needed conversions",
+    "redundant conversion in else branch"
+);
+
+test_file!(
+    len_comparison,
+    len_comparison,
+    "annotated comparison of lengths",
+    "comparison of length with a constant",
+    "comparison of lengths"
+);
+
+test_file!(
+    recursion,
+    infinite_recursion,
+    "recursion with a base case",
+    "recursion without a base case",
+    "recursion with a panicking base case",
+    "recursion with an assert as base case"
+);
+
+test_file!(
+    panics,
+    panic_in_result,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("panic_in_result".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "panic in function returning a result",
+    "panic in function returning a value"
+);
+
+test_file!(
+    conversions,
+    in_range_literal_try_into,
+    "in range literal",
+    "literal converted for a function",
+    "literal converted for an extern function",
+    "out of range literal"
+);
+
+test_file!(
+    conversions,
+    extern_call_arguments,
+    config: CairoLintConfig { lint_extern_call_arguments: true, ..CairoLintConfig::default() },
+    "literal converted for an extern function"
+);
+
+test_file!(match_arms, unreachable_wildcard, "reachable wildcard", "unreachable wildcard");
+
+test_file!(ifs, if_let_else_panic, "if let else doing more than panicking", "if let else panic");
+
+test_file!(
+    arithmetic,
+    unchecked_arithmetic,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("unchecked_arithmetic".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "addition of a parameter and a constant",
+    "addition of two parameters",
+    "multiplication of two felt252 parameters"
+);
+
+test_file!(
+    assignments,
+    single_reassignment,
+    "reassignment after a use",
+    "reassignment before any use",
+    "reassignment followed by another one"
+);
+
+test_file!(match_arms, nested_match, "nested match with different fallbacks", "nested match with the same fallback");
+
+test_file!(
+    yoda_comparison,
+    yoda_comparison,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("yoda_comparison".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "constant on the left of a relational comparison",
+    "constant on the left of an equality",
+    "constant on the right"
+);
+
+test_file!(
+    parameters,
+    large_struct_by_value,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("large_struct_by_value".to_string(), true)]),
+        large_struct_threshold: 4,
+        ..CairoLintConfig::default()
+    },
+    "large struct taken as a snapshot",
+    "large struct taken by value",
+    "small struct taken by value"
+);
+
+test_file!(
+    parameters,
+    public_api_only,
+    config: CairoLintConfig {
+        public_api_only: true,
+        lints: BTreeMap::from([
+            ("large_struct_by_value".to_string(), true),
+            ("variant_named_like_enum".to_string(), true),
+        ]),
+        large_struct_threshold: 4,
+        ..CairoLintConfig::default()
+    },
+    "large struct taken by value in a pub and a private function",
+    "variant named like a pub and a private enum"
+);
+
+test_file!(
+    match_arms,
+    bool_match_wildcard,
+    "unreachable wildcard after both booleans",
+    "wildcard after a single boolean"
+);
+
+test_file!(
+    suppressions,
+    comment_directives,
+    "directive allowing all lints",
+    "directive allowing another lint",
+    "directive allowing several lints",
+    "directive allowing the lint"
+);
+
+test_file!(
+    assignments,
+    redundant_chain_binding,
+    "binding used after another statement",
+    "binding used once right after",
+    "err bound once",
+    "ok bound once",
+    "value bound once"
+);
+
+test_file!(match_arms, inverted_bool_match, "bool match mapping to other values", "inverted bool match");
+
+test_file!(returns, needless_return_block, "early return of a block", "return of a block at the end of the function");
+
+test_file!(
+    defaults,
+    default_comparison,
+    "comparison with a literal",
+    "comparison with the default of an integer",
+    "default on both sides of the comparison"
+);
+
+test_file!(ifs, if_assignments, "branches assigning different variables", "branches assigning the same variable");
+
+test_file!(generics, duplicate_bound, "different bounds", "duplicate anonymous bound");
+
+test_file!(
+    derives,
+    redundant_clone_derive,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("redundant_clone_derive".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "clone on a generic struct",
+    "clone on a struct with copy fields",
+    "clone on a struct with non copy fields",
+    "clone on an enum with copy variants",
+    "copy on a struct with copy fields"
+);
+
+test_file!(
+    match_arms,
+    unused_match_scrutinee,
+    "match with literal patterns",
+    "match without arms",
+    "wildcard match on a call",
+    "wildcard match on a computed value",
+    "wildcard match on a variable",
+    "wildcard match on arithmetic",
+    "wildcard match on a comparison of arithmetic"
+);
+
+test_file!(
+    loops,
+    infinite_while_loop,
+    "condition false from the start",
+    "condition on a parameter",
+    "condition true from the start",
+    "counter changed by a ref call",
+    "counter incremented in the body",
+    "loop with a break"
+);
+
+test_file!(
+    parameters,
+    copy_value_indirection,
+    "reference to a copy value modified",
+    "reference to a copy value never modified",
+    "snapshot of a copy value",
+    "snapshot of a non copy value"
+);
+
+test_file!(
+    ifs,
+    if_same_return,
+    "condition with a function call",
+    "if and else returning different values",
+    "if and else returning the same value",
+    "if returning another value than the following return",
+    "if returning the same value as the following return"
+);
+
+test_file!(
+    comments,
+    todo_comment,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("todo_comment".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "fixme comment before an item",
+    "marker inside a word",
+    "todo comment in a function"
+);
+
+test_file!(
+    magic_numbers,
+    repeated_magic_number,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("repeated_magic_number".to_string(), true)]),
+        magic_number_threshold: 2,
+        ..CairoLintConfig::default()
+    },
+    "ignored number repeated in several functions",
+    "number repeated in a single function",
+    "number repeated in many functions",
+    "number repeated in several functions"
+);
+
+test_file!(
+    naming,
+    variant_named_like_enum,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("variant_named_like_enum".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "variant named like its enum",
+    "variant named like the enum with a suffix"
+);
+
+test_file!(
+    flags,
+    felt252_flag,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("felt252_flag".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "felt252 compared to a short string",
+    "felt252 compared to zero",
+    "integer compared to zero"
+);
+
+test_file!(
+    duplicates,
+    duplicate_function_body,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("duplicate_function_body".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "functions with different bodies",
+    "functions with the same body",
+    "functions with the same single statement",
+    "three functions with the same body"
+);
+
+test_file!(
+    asserts,
+    assert_without_message,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("assert_without_message".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "assert with a message",
+    "assert without a message",
+    "assert_eq without a message"
+);
+
+test_file!(
+    self_comparison,
+    self_comparison,
+    "call compared to itself",
+    "member compared to itself",
+    "reassigned variable compared to its previous value",
+    "variable compared to a block shadowing it",
+    "variable compared to itself"
+);
+
+test_file!(
+    snapshots,
+    redundant_snapshot,
+    "snapshot of a snapshot",
+    "snapshot of a snapshot parameter",
+    "snapshot of a value"
+);
+
+test_file!(
+    loops,
+    arithmetic_series_loop,
+    config: CairoLintConfig {
+        lints: BTreeMap::from([("arithmetic_series_loop".to_string(), true)]),
+        ..CairoLintConfig::default()
+    },
+    "counter incremented by two",
+    "product of a range",
+    "sum of a range",
+    "sum with explicit additions"
+);
+
+test_file!(
+    snapshots,
+    clone_in_snapshot_match,
+    "clone of a snapshot bound by a let",
+    "clone passed as a snapshot",
+    "clone returned by an arm",
+    "clone used by a snapshot method"
+);
+
+test_file!(
+    ifs,
+    if_let_chain,
+    "if let chain on different values",
+    "if let chain on the same value",
+    "if let chain with a boolean condition",
+    "single if let"
+);
+
+test_file!(
+    literals,
+    literal_suffix_mismatch,
+    "literal without a suffix",
+    "suffix aliasing the annotation",
+    "suffix matching the annotation",
+    "suffix not matching the annotation",
+    "suffix without an annotation"
+);
+
+test_file!(
+    collections,
+    discarded_collection,
+    "array bound to a variable",
+    "array explicitly discarded",
+    "discarded array",
+    "discarded number",
+    "discarded span"
+);
+
+test_file!(
+    bounds,
+    constant_comparison,
+    "bound on the left",
+    "converted value compared past its bounds",
+    "converted value compared within its bounds",
+    "signed value compared to zero",
+    "unsigned value compared to zero"
+);
+
+test_file!(
+    loops,
+    while_let_next,
+    "while let next",
+    "while let next advancing the iterator in the body",
+    "while let next with full path",
+    "while let next without using the element",
+    "while let pop front"
+);
+
+test_file!(
+    returns,
+    needless_unit_return,
+    "bare return after a comment",
+    "bare return at the end of a branch",
+    "bare return at the end of a function",
+    "bare return at the end of a function returning unit",
+    "early bare return",
+    "return of unit at the end of a function"
+);
+
+test_file!(
+    tautologies,
+    always_true_or,
+    "and with true",
+    "or with a signed value compared to zero",
+    "or with an unsigned value compared to zero",
+    "or with true",
+    "or without tautology",
+    "true or"
+);
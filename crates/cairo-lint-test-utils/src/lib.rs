@@ -1,21 +1,41 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
-use cairo_lang_diagnostics::Diagnostics;
-use cairo_lang_filesystem::db::{init_dev_corelib, FilesGroup};
-use cairo_lang_filesystem::ids::{CrateId, FileLongId};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, Severity};
+use cairo_lang_filesystem::db::{init_dev_corelib, CrateConfiguration, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory, FileId, FileLongId};
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::plugin::PluginSuite;
 use cairo_lang_semantic::SemanticDiagnostic;
-use cairo_lang_test_utils::parse_test_file::Test;
+use cairo_lang_test_utils::parse_test_file::{parse_test_file, Test};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use cairo_lang_utils::LookupIntern;
+use cairo_lang_utils::{LookupIntern, Upcast};
 
 pub struct Tests {
     pub tests: OrderedHashMap<String, Test>,
     pub should_fix: bool,
 }
+
+/// What the lints produce for the code of a test of a fixture file.
+pub struct FixtureOutput {
+    /// The rendered diagnostics.
+    pub diagnostics: String,
+    /// The code with the fixes applied.
+    pub fixed: String,
+    /// The mismatches with the inline annotations of the code, see [`check_inline_annotations`].
+    pub annotations: Result<(), String>,
+}
+
+/// Lints the code of a test, given its name and its code.
+pub type LintFixture = fn(&str, &str) -> FixtureOutput;
+
+/// The fixture files registered by [`test_file!`], with the function linting their tests.
+pub static FIXTURES: Mutex<Vec<(&str, LintFixture)>> = Mutex::new(Vec::new());
+
 pub fn get_diags(crate_id: CrateId, db: &mut RootDatabase) -> Vec<Diagnostics<SemanticDiagnostic>> {
     init_dev_corelib(db, PathBuf::from(std::env::var("CORELIB_PATH").unwrap()));
     let mut diagnostics = Vec::new();
@@ -33,9 +53,199 @@ pub fn get_diags(crate_id: CrateId, db: &mut RootDatabase) -> Vec<Diagnostics<Se
     }
     diagnostics
 }
+
+/// Creates a database with the corelib and the plugins of `suite` on top of the default ones, and
+/// a crate in the `root` directory. The directory is never read from the disk: the content of its
+/// files is overridden with `files`, given by their path relative to `root`. The crate is named
+/// after the last component of `root`.
+pub fn setup_crate(root: &str, files: &[(&str, &str)], suite: PluginSuite) -> (RootDatabase, CrateId) {
+    let mut db =
+        RootDatabase::builder().with_plugin_suite(get_default_plugin_suite()).with_plugin_suite(suite).build().unwrap();
+    init_dev_corelib(&mut db, PathBuf::from(std::env::var("CORELIB_PATH").unwrap()));
+    let name = root.rsplit('/').next().unwrap();
+    let crate_id = Upcast::<dyn FilesGroup>::upcast(&db).intern_crate(CrateLongId::Real(name.into()));
+    db.set_crate_config(crate_id, Some(CrateConfiguration::default_for_root(Directory::Real(root.into()))));
+    for (path, content) in files {
+        set_file(&mut db, root, path, content);
+    }
+    (db, crate_id)
+}
+
+/// Root directory of the crates of the tests, named `test_crate`. It's never read from the disk,
+/// the content of the crates being overridden.
+pub const CRATE_ROOT: &str = "/test_crate";
+
+/// Creates a crate in [`CRATE_ROOT`] made of a `lib.cairo` file with `content`, see
+/// [`setup_crate`].
+pub fn setup_single_file_crate(content: &str, suite: PluginSuite) -> (RootDatabase, CrateId) {
+    setup_crate(CRATE_ROOT, &[("lib.cairo", content)], suite)
+}
+
+/// Overrides the content of a file of a crate created by [`setup_crate`].
+pub fn set_file(db: &mut RootDatabase, root: &str, path: &str, content: &str) {
+    let file_id = FileId::new(db.upcast(), PathBuf::from(root).join(path));
+    db.override_file_content(file_id, Some(content.into()));
+}
+
+/// A diagnostic expected by a `//~ LEVEL message` comment in the cairo code of a test.
+///
+/// The annotation applies to its own line, each `^` right after `//~` moving it one line up (e.g.
+/// `//~^ WARNING message` applies to the line above).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedDiagnostic {
+    /// 0-based line of the diagnostic.
+    pub line: usize,
+    pub severity: Severity,
+    /// Substring of the message of the diagnostic.
+    pub message: String,
+}
+
+pub fn parse_inline_annotations(code: &str) -> Result<Vec<ExpectedDiagnostic>, String> {
+    let mut expected = Vec::new();
+    for (line_index, line) in code.lines().enumerate() {
+        let Some(start) = line.find("//~") else {
+            continue;
+        };
+        let annotation = &line[start + 3..];
+        let carets = annotation.chars().take_while(|c| *c == '^').count();
+        let annotation = annotation[carets..].trim();
+        let (level, message) = annotation.split_once(' ').unwrap_or((annotation, ""));
+        let severity = match level {
+            "WARNING" => Severity::Warning,
+            "ERROR" => Severity::Error,
+            _ => return Err(format!("invalid level `{level}` in annotation on line {}", line_index + 1)),
+        };
+        let Some(line) = line_index.checked_sub(carets) else {
+            return Err(format!("annotation on line {} points before the start of the file", line_index + 1));
+        };
+        expected.push(ExpectedDiagnostic { line, severity, message: message.trim().to_string() });
+    }
+    Ok(expected)
+}
+
+/// Checks that the diagnostics emitted for some code match its inline annotations, see
+/// [`ExpectedDiagnostic`]. Code without annotations isn't checked.
+///
+/// Returns an error listing the unexpected diagnostics and the annotations without a diagnostic.
+pub fn check_inline_annotations(
+    db: &RootDatabase,
+    code: &str,
+    diagnostics: &[SemanticDiagnostic],
+) -> Result<(), String> {
+    let mut expected = parse_inline_annotations(code)?;
+    if expected.is_empty() {
+        return Ok(());
+    }
+    let mut errors = Vec::new();
+    for diagnostic in diagnostics {
+        let location = diagnostic.location(db.upcast());
+        let line = location.span.position_in_file(db.upcast(), location.file_id).map(|position| position.start.line);
+        let message = diagnostic.format(db);
+        let matching_annotation = expected.iter().position(|annotation| {
+            Some(annotation.line) == line
+                && annotation.severity == diagnostic.severity()
+                && message.contains(&annotation.message)
+        });
+        match (matching_annotation, line) {
+            (Some(index), _) => {
+                expected.remove(index);
+            }
+            (None, Some(line)) => errors.push(format!("unexpected diagnostic on line {}: {message}", line + 1)),
+            (None, None) => errors.push(format!("unexpected diagnostic: {message}")),
+        }
+    }
+    errors.extend(expected.into_iter().map(|annotation| {
+        format!(
+            "no diagnostic for annotation on line {}: {:?} {}",
+            annotation.line + 1,
+            annotation.severity,
+            annotation.message
+        )
+    }));
+    if errors.is_empty() { Ok(()) } else { Err(errors.join("\n")) }
+}
+
+/// Lists the differences between the rendered diagnostics expected by a test and the ones it got,
+/// one line per diagnostic that appeared, disappeared, moved, or whose message or span changed.
+///
+/// Diagnostics are paired by location and message first, then by location only for the changed
+/// messages, and by message only for the moved diagnostics.
+pub fn diagnostics_drift(expected: &str, actual: &str) -> Vec<String> {
+    let mut expected = rendered_diagnostics(expected);
+    let mut changes = Vec::new();
+    for (header, location, span) in rendered_diagnostics(actual) {
+        let paired = expected
+            .iter()
+            .position(|(expected_header, expected_location, _)| {
+                *expected_header == header && *expected_location == location
+            })
+            .or_else(|| expected.iter().position(|(_, expected_location, _)| *expected_location == location))
+            .or_else(|| expected.iter().position(|(expected_header, _, _)| *expected_header == header));
+        let Some(index) = paired else {
+            changes.push(format!("new at {location}: {header}"));
+            continue;
+        };
+        let (expected_header, expected_location, expected_span) = expected.remove(index);
+        if expected_header != header {
+            changes.push(format!("message changed at {location}: {expected_header} -> {header}"));
+        } else if expected_location != location {
+            changes.push(format!("moved from {expected_location} to {location}: {header}"));
+        } else if expected_span != span {
+            changes.push(format!("span changed at {location}: {header}"));
+        }
+    }
+    changes.extend(expected.into_iter().map(|(header, location, _)| format!("gone from {location}: {header}")));
+    changes
+}
+
+/// Lints the tests of all the fixture files registered by [`test_file!`] and lists the differences
+/// with their expectations, see [`diagnostics_drift`], along with the tests whose fixed code
+/// changed and the mismatches of their inline annotations. Each line starts with the file and the
+/// name of its test, the lines being sorted.
+///
+/// Unlike the tests, nothing fails or gets rewritten: the report is meant to review the changes of
+/// the lints.
+pub fn fixtures_drift() -> Vec<String> {
+    let fixtures = FIXTURES.lock().unwrap().clone();
+    let mut report = Vec::new();
+    for (file_name, lint) in fixtures {
+        for (test_name, test) in parse_test_file(Path::new(file_name)).unwrap().iter() {
+            let output = lint(test_name, &test.attributes["cairo_code"]);
+            let mut drift = diagnostics_drift(&test.attributes["diagnostics"], &output.diagnostics);
+            if output.fixed != test.attributes["fixed"] {
+                drift.push("fixed code changed".to_string());
+            }
+            drift.extend(output.annotations.err());
+            report.extend(drift.into_iter().map(|change| format!("{file_name}: {test_name}: {change}")));
+        }
+    }
+    report.sort();
+    report
+}
+
+/// Splits rendered diagnostics into their first line with the severity and the message, their
+/// location, and the lines showing their span.
+fn rendered_diagnostics(rendered: &str) -> Vec<(&str, &str, String)> {
+    let mut diagnostics: Vec<(&str, &str, String)> = Vec::new();
+    for line in rendered.lines() {
+        if line.starts_with("warning: ") || line.starts_with("error: ") {
+            diagnostics.push((line, "", String::new()));
+        } else if let Some((_, location, span)) = diagnostics.last_mut() {
+            match line.trim_start().strip_prefix("--> ") {
+                Some(line_location) if location.is_empty() => *location = line_location,
+                _ => {
+                    span.push_str(line);
+                    span.push('\n');
+                }
+            }
+        }
+    }
+    diagnostics
+}
+
 #[macro_export]
 macro_rules! test_file {
-    ($lint_group: ident, $file_path:ident, $($test_name:expr),*) => {
+    ($lint_group: ident, $file_path:ident, config: $config:expr, $($test_name:expr),*) => {
 
         paste ! {
 
@@ -55,21 +265,27 @@ macro_rules! test_file {
                 }
             }
 
-            $(#[test_case($test_name; $test_name)])*
-            fn [<$lint_group _ $file_path>](test_name: &str) {
-                let test = & [<PARSED_TEST_FILE_ $file_path:upper>][test_name];
-                let is_fix_mode = std::env::var("FIX_TESTS") == Ok("1".into());
-                let mut file = test.attributes["cairo_code"].clone();
+            #[ctor]
+            fn [<register_ $lint_group $file_path _test_file>]() {
+                $crate::FIXTURES
+                    .lock()
+                    .unwrap()
+                    .push(([<TEST_FILENAME_ $file_path:upper>], [<lint_ $lint_group _ $file_path>]));
+            }
+
+            fn [<lint_ $lint_group _ $file_path>](test_name: &str, code: &str) -> $crate::FixtureOutput {
+                let mut file = code.to_string();
                 let mut db = RootDatabase::builder()
                     .with_plugin_suite(get_default_plugin_suite())
                     .with_plugin_suite(test_plugin_suite())
-                    .with_plugin_suite(cairo_lint_plugin_suite())
+                    .with_plugin_suite(cairo_lint_plugin_suite_with_config($config))
                     .build()
                     .unwrap();
 
                 let diags = get_diags(setup_test_crate_ex(db.upcast(), &file, Some(CRATE_CONFIG)), &mut db);
                 // Transform Vec<Diagnostics<Semantic>> into Vec<Semantic>
                 let semantic_diags: Vec<_> = diags.clone().into_iter().flat_map(|diag| diag.get_all()).collect();
+                let annotations = $crate::check_inline_annotations(&db, &file, &semantic_diags);
                 let unused_imports: HashMap<FileId, HashMap<SyntaxNode, ImportFix>> =
                     collect_unused_imports(&db, &semantic_diags);
                 let mut fixes = if unused_imports.keys().len() > 0 {
@@ -99,18 +315,31 @@ macro_rules! test_file {
                 }
                 let renderer = Renderer::plain();
                 let formatted_diags =
-                    diags.into_iter().flat_map(|diags| diags.get_all().iter().map(|diag| format_diagnostic(diag, &db, &renderer)).collect::<Vec<_>>()).collect::<String>().trim().to_string();
+                    diags.into_iter().flat_map(|diags| diags.get_all().iter().map(|diag| format_diagnostic(diag, &db, &renderer, None)).collect::<Vec<_>>()).collect::<String>().trim().to_string();
+                $crate::FixtureOutput { diagnostics: formatted_diags, fixed: file, annotations }
+            }
+
+            $(#[test_case($test_name; $test_name)])*
+            fn [<$lint_group _ $file_path>](test_name: &str) {
+                let test = & [<PARSED_TEST_FILE_ $file_path:upper>][test_name];
+                let is_fix_mode = std::env::var("FIX_TESTS") == Ok("1".into());
+                let output = [<lint_ $lint_group _ $file_path>](test_name, &test.attributes["cairo_code"]);
                 if is_fix_mode {
                     let mut new_test = test.clone();
-                    new_test.attributes.insert("diagnostics".to_string(), formatted_diags.clone());
-                    new_test.attributes.insert("fixed".to_string(), file.clone());
+                    new_test.attributes.insert("diagnostics".to_string(), output.diagnostics.clone());
+                    new_test.attributes.insert("fixed".to_string(), output.fixed.clone());
                     let mut new_tests = [<FIXED_TEST_FILE_ $file_path:upper>].lock().unwrap();
                     new_tests.should_fix = true;
                     new_tests.tests.insert(test_name.to_string(), new_test);
+                } else if let Err(error) = &output.annotations {
+                    panic!("{error}");
                 }
-                assert_eq!(formatted_diags, test.attributes["diagnostics"]);
-                assert_eq!(file, test.attributes["fixed"]);
+                assert_eq!(output.diagnostics, test.attributes["diagnostics"]);
+                assert_eq!(output.fixed, test.attributes["fixed"]);
             }
         }
     };
+    ($lint_group: ident, $file_path:ident, $($test_name:expr),*) => {
+        $crate::test_file!($lint_group, $file_path, config: CairoLintConfig::default(), $($test_name),*);
+    };
 }